@@ -0,0 +1,46 @@
+//! Compares the `Ord` implementation of `OcidV0`.
+//!
+//! Run with and without the `nightly-simd` feature to compare the SIMD and
+//! scalar paths:
+//!
+//! ```sh
+//! cargo +nightly bench --bench cmp
+//! cargo +nightly bench --bench cmp --features nightly-simd
+//! ```
+
+use std::hint::black_box;
+
+use ocid::OcidV0;
+
+mod util;
+
+fn main() {
+    let a = OcidV0::new(b"hello, world").unwrap();
+
+    let mut bytes = *a.as_bytes();
+    bytes[1] ^= 1;
+    let first = OcidV0::from_bytes(bytes).unwrap();
+
+    let mut bytes = *a.as_bytes();
+    bytes[bytes.len() - 1] ^= 1;
+    let last = OcidV0::from_bytes(bytes).unwrap();
+
+    util::bench("cmp, first body byte differs", 10_000_000, || {
+        black_box(&a).cmp(black_box(&first))
+    });
+    util::bench("cmp, last body byte differs", 10_000_000, || {
+        black_box(&a).cmp(black_box(&last))
+    });
+    util::bench("cmp, equal", 10_000_000, || {
+        black_box(&a).cmp(black_box(&a))
+    });
+
+    let ids: Vec<OcidV0> = (0..100_000u32)
+        .map(|i| OcidV0::new(&i.to_le_bytes()).unwrap())
+        .collect();
+    util::bench("sort_unstable, 100k IDs", 20, || {
+        let mut ids = ids.clone();
+        ids.sort_unstable();
+        ids
+    });
+}
@@ -0,0 +1,29 @@
+//! Compares `OcidV0::cmp_then_eq` against calling `cmp` and `==` separately.
+//!
+//! ```sh
+//! cargo bench --bench cmp_then_eq
+//! ```
+
+use std::hint::black_box;
+
+use ocid::OcidV0;
+
+mod util;
+
+fn main() {
+    let a = OcidV0::new(b"hello, world").unwrap();
+
+    let mut bytes = *a.as_bytes();
+    bytes[bytes.len() - 1] ^= 1;
+    let last = OcidV0::from_bytes(bytes).unwrap();
+
+    for &(name, b) in &[("equal", &a), ("last body byte differs", &last)] {
+        util::bench(&format!("cmp_then_eq, {}", name), 10_000_000, || {
+            black_box(&a).cmp_then_eq(black_box(b))
+        });
+        util::bench(&format!("cmp and ==, {}", name), 10_000_000, || {
+            let (a, b) = (black_box(&a), black_box(b));
+            (a.cmp(b), a == b)
+        });
+    }
+}
@@ -0,0 +1,33 @@
+//! Compares `OcidV0::new_with` against `OcidV0::new` and against creating a
+//! new `blake3::Hasher` for each buffer.
+//!
+//! ```sh
+//! cargo bench --bench new_with
+//! ```
+
+use std::hint::black_box;
+
+use ocid::OcidV0;
+
+mod util;
+
+fn main() {
+    for &(len, iters) in &[(64, 2_000_000), (1024, 500_000), (16384, 50_000)] {
+        let content = vec![0xAB; len];
+        let content = &content[..];
+
+        util::bench(&format!("new, {} bytes", len), iters, || {
+            OcidV0::new(black_box(content))
+        });
+
+        util::bench(&format!("new Hasher, {} bytes", len), iters, || {
+            let mut hasher = blake3::Hasher::new();
+            hasher.update(black_box(content)).finalize()
+        });
+
+        let mut hasher = blake3::Hasher::new();
+        util::bench(&format!("new_with, {} bytes", len), iters, || {
+            OcidV0::new_with(&mut hasher, black_box(content))
+        });
+    }
+}
@@ -0,0 +1,25 @@
+use std::{hint::black_box, time::Instant};
+
+/// Prints the fastest average time in nanoseconds per call of `f` over 5 runs of `iters`
+/// calls each.
+///
+/// This avoids a dependency on a benchmarking framework. Run with
+/// `cargo bench --bench <name>` to get an optimized build.
+pub fn bench<T>(name: &str, iters: u32, mut f: impl FnMut() -> T) {
+    // Warm up caches and the branch predictor.
+    for _ in 0..iters / 10 {
+        black_box(f());
+    }
+
+    let best = (0..5)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iters {
+                black_box(f());
+            }
+            start.elapsed().as_nanos() as f64 / f64::from(iters)
+        })
+        .fold(f64::INFINITY, f64::min);
+
+    println!("{:<40} {:>14.1} ns", name, best);
+}
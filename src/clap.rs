@@ -0,0 +1,106 @@
+//! [`clap`] integration for parsing IDs from command-line arguments.
+//!
+//! Because the [Base64] encoding of an [`OcidV0`] always starts with `-`,
+//! arguments must set [`allow_hyphen_values`] for clap to not treat IDs as
+//! flags. Alternatively, users can pass them as `--id=<ID>`.
+//!
+//! ```
+//! use clap::{Arg, Command};
+//! use ocid::{clap::OcidV0ValueParser, OcidV0};
+//!
+//! let cmd = Command::new("ocean").arg(
+//!     Arg::new("id")
+//!         .long("id")
+//!         .allow_hyphen_values(true)
+//!         .value_parser(OcidV0ValueParser),
+//! );
+//! # let id = OcidV0::new(b"hello").unwrap().to_string();
+//! # let matches = cmd.get_matches_from(["ocean", "--id", &id]);
+//! # assert!(matches.get_one::<OcidV0>("id").is_some());
+//! ```
+//!
+//! [`clap`]:                https://docs.rs/clap/4
+//! [`OcidV0`]:              ../struct.OcidV0.html
+//! [`allow_hyphen_values`]: https://docs.rs/clap/4/clap/struct.Arg.html#method.allow_hyphen_values
+//!
+//! [Base64]: https://en.wikipedia.org/wiki/Base64
+
+use std::ffi::OsStr;
+
+use clap::{builder::TypedValueParser, Arg, Command, Error};
+
+use crate::OcidV0;
+
+/// A [`TypedValueParser`] for [`OcidV0`] arguments in their [Base64]
+/// encoding.
+///
+/// Malformed input results in clap's usual "invalid value" error, with the
+/// reason given by the [`ParseError`] of the [`FromStr`] implementation.
+///
+/// [`TypedValueParser`]: https://docs.rs/clap/4/clap/builder/trait.TypedValueParser.html
+/// [`OcidV0`]:           ../struct.OcidV0.html
+/// [`ParseError`]:       ../v0/enum.ParseError.html
+/// [`FromStr`]:          https://doc.rust-lang.org/std/str/trait.FromStr.html
+///
+/// [Base64]: https://en.wikipedia.org/wiki/Base64
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OcidV0ValueParser;
+
+impl TypedValueParser for OcidV0ValueParser {
+    type Value = OcidV0;
+
+    #[inline]
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<OcidV0, Error> {
+        let parse = |s: &str| s.parse::<OcidV0>();
+        parse.parse_ref(cmd, arg, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::error::ErrorKind;
+
+    fn cmd() -> Command {
+        Command::new("ocean").arg(
+            Arg::new("id")
+                .long("id")
+                .allow_hyphen_values(true)
+                .value_parser(OcidV0ValueParser),
+        )
+    }
+
+    #[test]
+    fn parse_id_arg() {
+        let id = OcidV0::rand(rand_core::OsRng);
+        let b64 = id.to_string();
+
+        let matches = cmd().get_matches_from(["ocean", "--id", &b64]);
+        assert_eq!(matches.get_one::<OcidV0>("id"), Some(&id));
+
+        let arg = format!("--id={}", b64);
+        let matches = cmd().get_matches_from(["ocean", &arg]);
+        assert_eq!(matches.get_one::<OcidV0>("id"), Some(&id));
+    }
+
+    #[test]
+    fn malformed() {
+        let error = cmd()
+            .try_get_matches_from(["ocean", "--id", "bad"])
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::ValueValidation);
+
+        let message = error.to_string();
+        assert!(message.contains("'bad'"), "{}", message);
+        assert!(
+            message.contains("expected 52 Base64 characters but found 3"),
+            "{}",
+            message,
+        );
+    }
+}
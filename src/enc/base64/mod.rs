@@ -10,22 +10,166 @@
 //! | 37     | `_`
 //! | 38-63  | `abcdefghijklmnopqrstuvwxyz`
 //!
-//! [Base64]: https://en.wikipedia.org/wiki/Base64
+//! # Standard Alphabet
+//!
+//! For interoperability with external tools, the `_standard_` functions use
+//! the URL-safe alphabet described in [RFC 4648 §5] instead:
+//!
+//! | Values | Characters
+//! | :----- | :---------
+//! | 0-25   | `ABCDEFGHIJKLMNOPQRSTUVWXYZ`
+//! | 26-51  | `abcdefghijklmnopqrstuvwxyz`
+//! | 52-61  | `0123456789`
+//! | 62     | `-`
+//! | 63     | `_`
+//!
+//! Because this alphabet is not sorted, encodings made with it **do not** have
+//! the same [lexicographical order] as the bytes they encode.
+//!
+//! [Base64]:                https://en.wikipedia.org/wiki/Base64
+//! [lexicographical order]: https://en.wikipedia.org/wiki/Lexicographical_order
+//! [RFC 4648 §5]:           https://tools.ietf.org/html/rfc4648#section-5
 
-use core::{mem::MaybeUninit, str};
+use core::{fmt, mem::MaybeUninit, str};
 
 #[cfg(test)]
 mod tests;
 
 const LEN_39: usize = 39 / 3 * 4;
 
-// URL-safe character set with lexicographical ordering.
-const ALPHABET: [u8; 64] = *b"-\
+/// The URL-safe alphabet used to encode IDs as [Base64], for validators and
+/// highlighters that must match this crate.
+///
+/// The characters are in ASCII order, and so encodings have the same order as
+/// the bytes they encode. Character `i` encodes the 6-bit value `i`.
+///
+/// [Base64]: https://en.wikipedia.org/wiki/Base64
+pub const ALPHABET: [u8; 64] = *b"-\
                               0123456789\
                               ABCDEFGHIJKLMNOPQRSTUVWXYZ\
                               _\
                               abcdefghijklmnopqrstuvwxyz";
 
+// URL-safe character set from RFC 4648 §5.
+const STANDARD_ALPHABET: [u8; 64] = *b"ABCDEFGHIJKLMNOPQRSTUVWXYZ\
+                                       abcdefghijklmnopqrstuvwxyz\
+                                       0123456789\
+                                       -_";
+
+/// An error returned when decoding [Base64] fails.
+///
+/// [Base64]: https://en.wikipedia.org/wiki/Base64
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The input has the given length, which is not the expected length.
+    InvalidLength(usize),
+    /// The input has a byte at `index` that is not in the alphabet.
+    InvalidChar {
+        /// The offset of `byte` within the input.
+        index: usize,
+        /// The invalid byte.
+        byte: u8,
+    },
+    /// The decoded ID has the given version, which is not 0.
+    UnexpectedVersion(u8),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeError::InvalidLength(len) => write!(
+                f,
+                "expected {} Base64 characters but found {}",
+                LEN_39, len,
+            ),
+            DecodeError::InvalidChar { index, byte } if byte.is_ascii() => {
+                write!(
+                    f,
+                    "invalid Base64 character {:?} at index {}",
+                    byte as char, index,
+                )
+            }
+            DecodeError::InvalidChar { index, byte } => write!(
+                f,
+                "invalid Base64 byte 0x{:02x} at index {}",
+                byte, index,
+            ),
+            DecodeError::UnexpectedVersion(version) => write!(
+                f,
+                "expected OCID version 0 but found version {}",
+                version,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for DecodeError {}
+
+// Marks bytes in decode tables that are not in the alphabet.
+const INVALID: u8 = 0xFF;
+
+// Reverse mapping of `ALPHABET`, from byte to 6-bit value.
+const DECODE_TABLE: [u8; 256] = decode_table(&ALPHABET);
+
+// Reverse mapping of `STANDARD_ALPHABET`, from byte to 6-bit value.
+const STANDARD_DECODE_TABLE: [u8; 256] = decode_table(&STANDARD_ALPHABET);
+
+const fn decode_table(alphabet: &[u8; 64]) -> [u8; 256] {
+    let mut table = [INVALID; 256];
+    let mut i = 0;
+    while i < alphabet.len() {
+        table[alphabet[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+/// Returns the 6-bit value of `byte` in the alphabet.
+#[inline]
+fn decode_char(byte: u8) -> Option<u8> {
+    match DECODE_TABLE[byte as usize] {
+        INVALID => None,
+        value => Some(value),
+    }
+}
+
+/// Returns whether `byte` is in the alphabet.
+#[inline]
+pub(crate) fn is_valid_char(byte: u8) -> bool {
+    decode_char(byte).is_some()
+}
+
+/// Decodes the first byte of the base-64 `input`, which needs only its first
+/// two characters.
+///
+/// Returns `None` if `input` is too short or starts with invalid characters.
+#[inline]
+pub fn decode_first_byte(input: &[u8]) -> Option<u8> {
+    match input {
+        [a, b, ..] => Some(decode_char(*a)? << 2 | decode_char(*b)? >> 4),
+        _ => None,
+    }
+}
+
+/// Returns the checksum character of a valid base-64 encoding of 39 bytes.
+///
+/// This is the sum of all values weighted by odd numbers, modulo 64. Because
+/// odd weights are invertible modulo 64, changing any single character always
+/// changes the checksum.
+pub(crate) fn checksum_39(encoded: &[u8]) -> u8 {
+    debug_assert_eq!(encoded.len(), LEN_39);
+
+    let sum = encoded.iter().enumerate().fold(0u32, |sum, (i, &byte)| {
+        let value = decode_char(byte).unwrap_or(0);
+        let weight = 2 * i as u32 + 1;
+        sum.wrapping_add(weight * u32::from(value))
+    });
+    ALPHABET[(sum % 64) as usize]
+}
+
 /// Encodes the 39 base-8 `bytes` into `buf` as base-64, returning the encoded
 /// UTF-8 string.
 #[inline]
@@ -39,9 +183,33 @@ pub fn encode_base8_39<'a>(
 
 /// Encodes the 39 base-8 `bytes` into `buf` as base-64, returning the encoded
 /// UTF-8 string.
+#[inline]
 pub fn encode_base8_39_uninit<'a>(
     bytes: &[u8; 39],
     buf: &'a mut [MaybeUninit<u8>; LEN_39],
+) -> &'a mut str {
+    encode_base8_39_with(bytes, buf, &ALPHABET)
+}
+
+/// Encodes the 39 base-8 `bytes` into `buf` as base-64 using the
+/// [standard alphabet](index.html#standard-alphabet), returning the encoded
+/// UTF-8 string.
+///
+/// The result **does not** have the same lexicographical order as `bytes`.
+#[inline]
+pub fn encode_standard_39<'a>(
+    bytes: &[u8; 39],
+    buf: &'a mut [u8; LEN_39],
+) -> &'a mut str {
+    let buf = unsafe { &mut *(buf.as_mut_ptr() as *mut _) };
+    encode_base8_39_with(bytes, buf, &STANDARD_ALPHABET)
+}
+
+#[inline]
+fn encode_base8_39_with<'a>(
+    bytes: &[u8; 39],
+    buf: &'a mut [MaybeUninit<u8>; LEN_39],
+    alphabet: &[u8; 64],
 ) -> &'a mut str {
     #![allow(clippy::many_single_char_names)]
 
@@ -73,28 +241,28 @@ pub fn encode_base8_39_uninit<'a>(
             let offset = 0;
             $(
                 buf[offset] = MaybeUninit::new(
-                    ALPHABET[(($i >> 58) & LOW_SIX_BITS_64) as usize]
+                    alphabet[(($i >> 58) & LOW_SIX_BITS_64) as usize]
                 );
                 buf[offset + 1] = MaybeUninit::new(
-                    ALPHABET[(($i >> 52) & LOW_SIX_BITS_64) as usize]
+                    alphabet[(($i >> 52) & LOW_SIX_BITS_64) as usize]
                 );
                 buf[offset + 2] = MaybeUninit::new(
-                    ALPHABET[(($i >> 46) & LOW_SIX_BITS_64) as usize]
+                    alphabet[(($i >> 46) & LOW_SIX_BITS_64) as usize]
                 );
                 buf[offset + 3] = MaybeUninit::new(
-                    ALPHABET[(($i >> 40) & LOW_SIX_BITS_64) as usize]
+                    alphabet[(($i >> 40) & LOW_SIX_BITS_64) as usize]
                 );
                 buf[offset + 4] = MaybeUninit::new(
-                    ALPHABET[(($i >> 34) & LOW_SIX_BITS_64) as usize]
+                    alphabet[(($i >> 34) & LOW_SIX_BITS_64) as usize]
                 );
                 buf[offset + 5] = MaybeUninit::new(
-                    ALPHABET[(($i >> 28) & LOW_SIX_BITS_64) as usize]
+                    alphabet[(($i >> 28) & LOW_SIX_BITS_64) as usize]
                 );
                 buf[offset + 6] = MaybeUninit::new(
-                    ALPHABET[(($i >> 22) & LOW_SIX_BITS_64) as usize]
+                    alphabet[(($i >> 22) & LOW_SIX_BITS_64) as usize]
                 );
                 buf[offset + 7] = MaybeUninit::new(
-                    ALPHABET[(($i >> 16) & LOW_SIX_BITS_64) as usize]
+                    alphabet[(($i >> 16) & LOW_SIX_BITS_64) as usize]
                 );
 
                 #[allow(unused_variables)]
@@ -107,16 +275,169 @@ pub fn encode_base8_39_uninit<'a>(
 
     let offset = LEN_39 - 4;
     buf[offset] =
-        MaybeUninit::new(ALPHABET[((g >> 26) & LOW_SIX_BITS_32) as usize]);
+        MaybeUninit::new(alphabet[((g >> 26) & LOW_SIX_BITS_32) as usize]);
     buf[offset + 1] =
-        MaybeUninit::new(ALPHABET[((g >> 20) & LOW_SIX_BITS_32) as usize]);
+        MaybeUninit::new(alphabet[((g >> 20) & LOW_SIX_BITS_32) as usize]);
     buf[offset + 2] =
-        MaybeUninit::new(ALPHABET[((g >> 14) & LOW_SIX_BITS_32) as usize]);
+        MaybeUninit::new(alphabet[((g >> 14) & LOW_SIX_BITS_32) as usize]);
     buf[offset + 3] =
-        MaybeUninit::new(ALPHABET[((g >> 8) & LOW_SIX_BITS_32) as usize]);
+        MaybeUninit::new(alphabet[((g >> 8) & LOW_SIX_BITS_32) as usize]);
 
     unsafe {
         let buf = &mut *(buf as *mut _ as *mut [u8; LEN_39]);
         str::from_utf8_unchecked_mut(buf)
     }
 }
+
+/// Decodes the base-64 `input` into 39 base-8 bytes.
+///
+/// This is the inverse of [`encode_base8_39`](fn.encode_base8_39.html).
+#[inline]
+pub fn decode_base8_39(input: &[u8]) -> Result<[u8; 39], DecodeError> {
+    decode_base8_39_with(input, &DECODE_TABLE)
+}
+
+/// Decodes the base-64 `input` using the
+/// [standard alphabet](index.html#standard-alphabet) into 39 base-8 bytes.
+///
+/// This is the inverse of [`encode_standard_39`](fn.encode_standard_39.html).
+#[inline]
+pub fn decode_standard_39(input: &[u8]) -> Result<[u8; 39], DecodeError> {
+    decode_base8_39_with(input, &STANDARD_DECODE_TABLE)
+}
+
+fn decode_base8_39_with(
+    input: &[u8],
+    table: &[u8; 256],
+) -> Result<[u8; 39], DecodeError> {
+    if input.len() != LEN_39 {
+        return Err(DecodeError::InvalidLength(input.len()));
+    }
+
+    let mut bytes = [0u8; 39];
+
+    // Every 4 characters map to exactly 3 bytes, so there are no padding bits.
+    let chunks = input.chunks_exact(4).zip(bytes.chunks_exact_mut(3));
+    for (i, (chars, out)) in chunks.enumerate() {
+        let mut n = 0u32;
+
+        for (j, &byte) in chars.iter().enumerate() {
+            let value = match table[byte as usize] {
+                INVALID => {
+                    return Err(DecodeError::InvalidChar {
+                        index: i * 4 + j,
+                        byte,
+                    })
+                }
+                value => value,
+            };
+            n = (n << 6) | u32::from(value);
+        }
+
+        out.copy_from_slice(&n.to_be_bytes()[1..]);
+    }
+
+    Ok(bytes)
+}
+
+/// A streaming [Base64] decoder of 39 bytes.
+///
+/// Input may be [`push`](#method.push)ed in arbitrarily-sized chunks, such as
+/// when an ID arrives split across network reads. The decoded bytes are
+/// returned by [`finish`](#method.finish) once all 52 characters are seen.
+///
+/// [Base64]: https://en.wikipedia.org/wiki/Base64
+#[derive(Clone, Debug)]
+pub struct Base64Decoder {
+    bytes: [u8; 39],
+    len: usize,
+    acc: u32,
+    error: Option<DecodeError>,
+}
+
+impl Default for Base64Decoder {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Base64Decoder {
+    /// Creates a decoder that has not seen any input.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; 39],
+            len: 0,
+            acc: 0,
+            error: None,
+        }
+    }
+
+    /// Returns the number of characters decoded so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no characters have been decoded so far.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decodes the next chunk of `input`.
+    ///
+    /// Returns an error if `input` has a character not in the alphabet or if
+    /// more than 52 characters have been pushed in total. Once an error is
+    /// returned, all further calls fail with the same error.
+    pub fn push(&mut self, input: &[u8]) -> Result<(), DecodeError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        for (i, &byte) in input.iter().enumerate() {
+            let value = if self.len == LEN_39 {
+                Err(DecodeError::InvalidLength(self.len + input.len() - i))
+            } else {
+                decode_char(byte).ok_or(DecodeError::InvalidChar {
+                    index: self.len,
+                    byte,
+                })
+            };
+
+            let value = match value {
+                Ok(value) => value,
+                Err(error) => {
+                    self.error = Some(error);
+                    return Err(error);
+                }
+            };
+
+            self.acc = (self.acc << 6) | u32::from(value);
+            self.len += 1;
+
+            // Every 4 characters map to 3 bytes.
+            if self.len & 0b11 == 0 {
+                let start = (self.len / 4 - 1) * 3;
+                let acc = self.acc.to_be_bytes();
+                self.bytes[start..(start + 3)].copy_from_slice(&acc[1..]);
+                self.acc = 0;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the decoded bytes if exactly 52 characters were pushed.
+    #[inline]
+    pub fn finish(self) -> Result<[u8; 39], DecodeError> {
+        match self.error {
+            Some(error) => Err(error),
+            None if self.len != LEN_39 => {
+                Err(DecodeError::InvalidLength(self.len))
+            }
+            None => Ok(self.bytes),
+        }
+    }
+}
@@ -28,8 +28,162 @@ fn encode_base8_39() {
     }
 }
 
+#[test]
+fn decode_base8_39() {
+    let mut rng = rand_core::OsRng;
+    let mut buf = [0u8; LEN_39];
+
+    for _ in 0..2048 {
+        let mut bytes = [0u8; 39];
+        rng.fill_bytes(&mut bytes);
+
+        let encoded = super::encode_base8_39(&bytes, &mut buf);
+        let decoded = super::decode_base8_39(encoded.as_bytes());
+
+        assert_eq!(decoded, Ok(bytes));
+    }
+}
+
+#[test]
+fn decode_base8_39_invalid() {
+    let mut input = [b'A'; LEN_39];
+
+    assert_eq!(
+        super::decode_base8_39(&input[1..]),
+        Err(DecodeError::InvalidLength(LEN_39 - 1)),
+    );
+
+    input[17] = b'=';
+    assert_eq!(
+        super::decode_base8_39(&input),
+        Err(DecodeError::InvalidChar {
+            index: 17,
+            byte: b'=',
+        }),
+    );
+}
+
+#[test]
+fn decode_first_byte() {
+    let mut rng = rand_core::OsRng;
+    let mut buf = [0u8; LEN_39];
+
+    for _ in 0..256 {
+        let mut bytes = [0u8; 39];
+        rng.fill_bytes(&mut bytes);
+
+        let encoded = super::encode_base8_39(&bytes, &mut buf);
+        let first = super::decode_first_byte(&encoded.as_bytes()[..2]);
+
+        assert_eq!(first, Some(bytes[0]));
+    }
+
+    assert_eq!(super::decode_first_byte(b"-"), None);
+    assert_eq!(super::decode_first_byte(b"-="), None);
+}
+
+#[test]
+fn decoder() {
+    let mut rng = rand_core::OsRng;
+    let mut buf = [0u8; LEN_39];
+
+    for _ in 0..256 {
+        let mut bytes = [0u8; 39];
+        rng.fill_bytes(&mut bytes);
+
+        let mut encoded = super::encode_base8_39(&bytes, &mut buf).as_bytes();
+        let mut decoder = Base64Decoder::new();
+
+        // Push chunks of random sizes, including empty ones.
+        while !encoded.is_empty() {
+            let len = (rng.next_u32() as usize % 8).min(encoded.len());
+            let (chunk, rest) = encoded.split_at(len);
+            decoder.push(chunk).unwrap();
+            encoded = rest;
+        }
+
+        assert_eq!(decoder.len(), LEN_39);
+        assert_eq!(decoder.finish(), Ok(bytes));
+    }
+}
+
+#[test]
+fn decoder_invalid() {
+    let mut decoder = Base64Decoder::new();
+    decoder.push(&[b'A'; 40]).unwrap();
+    assert_eq!(
+        decoder.clone().finish(),
+        Err(DecodeError::InvalidLength(40)),
+    );
+
+    assert_eq!(
+        decoder.push(&[b'A'; 13]),
+        Err(DecodeError::InvalidLength(LEN_39 + 1)),
+    );
+    assert_eq!(
+        decoder.finish(),
+        Err(DecodeError::InvalidLength(LEN_39 + 1))
+    );
+
+    let mut decoder = Base64Decoder::new();
+    decoder.push(b"AAA").unwrap();
+    let error = DecodeError::InvalidChar {
+        index: 4,
+        byte: b'+',
+    };
+    assert_eq!(decoder.push(b"A+AA"), Err(error));
+    assert_eq!(decoder.push(b"AAAA"), Err(error));
+    assert_eq!(decoder.finish(), Err(error));
+}
+
+#[test]
+fn standard_39() {
+    let mut rng = rand_core::OsRng;
+    let mut base64_buf = [0u8; LEN_39 * 2];
+    let mut crate_buf = [0u8; LEN_39];
+
+    for _ in 0..2048 {
+        let mut bytes = [0u8; 39];
+        rng.fill_bytes(&mut bytes);
+
+        let base64_len = base64::encode_to_slice(
+            &bytes,
+            &mut base64_buf,
+            &STANDARD_ALPHABET,
+        );
+        let base64 = str::from_utf8(&base64_buf[..base64_len]).unwrap();
+
+        let encoded = super::encode_standard_39(&bytes, &mut crate_buf);
+        assert_eq!(encoded, base64);
+
+        let decoded = super::decode_standard_39(encoded.as_bytes());
+        assert_eq!(decoded, Ok(bytes));
+    }
+
+    // Characters are only valid in their own alphabet.
+    let input = [b'+'; LEN_39];
+    assert!(super::decode_standard_39(&input).is_err());
+    assert!(super::decode_base8_39(&input).is_err());
+}
+
+// Ensures that `DECODE_TABLE` is consistent with `ALPHABET`.
+#[test]
+fn decode_table() {
+    for (i, &byte) in ALPHABET.iter().enumerate() {
+        assert_eq!(DECODE_TABLE[byte as usize] as usize, i);
+    }
+
+    let valid = DECODE_TABLE.iter().filter(|&&v| v != INVALID).count();
+    assert_eq!(valid, ALPHABET.len());
+
+    for (i, &byte) in STANDARD_ALPHABET.iter().enumerate() {
+        assert_eq!(STANDARD_DECODE_TABLE[byte as usize] as usize, i);
+    }
+}
+
 // Sanity check that `ALPHABET` is indeed sorted.
 #[test]
+#[allow(clippy::needless_range_loop)]
 fn sorted_alphabet() {
     for i in 0..(ALPHABET.len() - 1) {
         let j = i + 1;
@@ -0,0 +1,147 @@
+//! [Hexadecimal] encoding/decoding.
+//!
+//! [Hexadecimal]: https://en.wikipedia.org/wiki/Hexadecimal
+
+use core::fmt;
+
+const LOWER: [u8; 16] = *b"0123456789abcdef";
+
+/// Encodes `bytes` into `buf` as lowercase hexadecimal.
+///
+/// # Panics
+///
+/// Panics if `buf` is not exactly twice as long as `bytes`.
+#[inline]
+pub fn encode_lower(bytes: &[u8], buf: &mut [u8]) {
+    assert_eq!(bytes.len() * 2, buf.len(), "buffer has the wrong length");
+
+    for (byte, out) in bytes.iter().zip(buf.chunks_exact_mut(2)) {
+        out[0] = LOWER[usize::from(byte >> 4)];
+        out[1] = LOWER[usize::from(byte & 0xF)];
+    }
+}
+
+/// An error returned when decoding [hexadecimal] fails.
+///
+/// [hexadecimal]: https://en.wikipedia.org/wiki/Hexadecimal
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The input has the given length, which is not the expected length.
+    InvalidLength {
+        /// The number of characters expected.
+        expected: usize,
+        /// The number of characters found.
+        found: usize,
+    },
+    /// The input has a byte at `index` that is not a hexadecimal digit.
+    InvalidChar {
+        /// The offset of `byte` within the input.
+        index: usize,
+        /// The invalid byte.
+        byte: u8,
+    },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DecodeError::InvalidLength { expected, found } => write!(
+                f,
+                "expected {} hexadecimal characters but found {}",
+                expected, found,
+            ),
+            DecodeError::InvalidChar { index, byte } if byte.is_ascii() => {
+                write!(
+                    f,
+                    "invalid hexadecimal character {:?} at index {}",
+                    byte as char, index,
+                )
+            }
+            DecodeError::InvalidChar { index, byte } => write!(
+                f,
+                "invalid hexadecimal byte 0x{:02x} at index {}",
+                byte, index,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for DecodeError {}
+
+/// Returns the 4-bit value of the hexadecimal digit `byte`.
+#[inline]
+fn decode_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes the hexadecimal `input` into `buf`, accepting either case.
+///
+/// Returns an error if `input` is not exactly twice as long as `buf` or if it
+/// contains a byte that is not a hexadecimal digit.
+#[inline]
+pub fn decode(input: &[u8], buf: &mut [u8]) -> Result<(), DecodeError> {
+    if input.len() != buf.len() * 2 {
+        return Err(DecodeError::InvalidLength {
+            expected: buf.len() * 2,
+            found: input.len(),
+        });
+    }
+
+    let digit = |index: usize| {
+        let byte = input[index];
+        decode_digit(byte).ok_or(DecodeError::InvalidChar { index, byte })
+    };
+
+    for (i, out) in buf.iter_mut().enumerate() {
+        *out = digit(i * 2)? << 4 | digit(i * 2 + 1)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let mut hex = [0u8; 512];
+        encode_lower(&bytes, &mut hex);
+
+        let mut decoded = [0u8; 256];
+        decode(&hex, &mut decoded).unwrap();
+        assert_eq!(&decoded[..], &bytes[..]);
+
+        let mut upper = hex;
+        upper.make_ascii_uppercase();
+        decode(&upper, &mut decoded).unwrap();
+        assert_eq!(&decoded[..], &bytes[..]);
+    }
+
+    #[test]
+    fn invalid() {
+        let mut buf = [0u8; 2];
+        assert_eq!(
+            decode(b"abc", &mut buf),
+            Err(DecodeError::InvalidLength {
+                expected: 4,
+                found: 3,
+            }),
+        );
+        assert_eq!(
+            decode(b"ab0g", &mut buf),
+            Err(DecodeError::InvalidChar {
+                index: 3,
+                byte: b'g',
+            }),
+        );
+    }
+}
@@ -1,3 +1,4 @@
 //! Encoding/decoding operations.
 
 pub mod base64;
+pub mod hex;
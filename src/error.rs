@@ -0,0 +1,65 @@
+use core::fmt;
+
+/// An error returned when constructing an [`Ocid`](enum.Ocid.html) or
+/// modifying stored IDs fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OcidError {
+    /// The ID has the given version, which is not supported.
+    UnknownVersion(u8),
+    /// The body of the ID has the wrong length for its version.
+    WrongBodyLen {
+        /// The ID version.
+        version: u8,
+        /// The body length expected for `version`.
+        expected: usize,
+        /// The body length found.
+        found: usize,
+    },
+    /// The index of an ID is not within the given number of IDs.
+    IndexOutOfBounds {
+        /// The index of the ID.
+        index: usize,
+        /// The number of IDs.
+        len: usize,
+    },
+    /// The given size is larger than the maximum for the ID version.
+    SizeTooLarge(u64),
+    /// The given number of bytes are left over after the last whole ID, such
+    /// as in a truncated or misaligned file.
+    TrailingBytes(usize),
+}
+
+impl fmt::Display for OcidError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OcidError::UnknownVersion(version) => {
+                write!(f, "unknown OCID version {}", version)
+            }
+            OcidError::WrongBodyLen {
+                version,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {} body bytes for OCID version {} but found {}",
+                expected, version, found,
+            ),
+            OcidError::IndexOutOfBounds { index, len } => write!(
+                f,
+                "OCID index {} is out of bounds for {} IDs",
+                index, len,
+            ),
+            OcidError::SizeTooLarge(size) => {
+                write!(f, "size {} is too large for an OCID", size)
+            }
+            OcidError::TrailingBytes(len) => {
+                write!(f, "{} bytes are left over after the last OCID", len)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for OcidError {}
@@ -0,0 +1,177 @@
+//! C ABI for version 0 IDs.
+//!
+//! All functions operate on [`RawOcidV0`], which is `#[repr(C)]` and has the
+//! same layout as 39 contiguous bytes.
+//!
+//! # Header
+//!
+//! A C header can be generated with [`cbindgen`] by enabling this feature when
+//! parsing the crate. The resulting declarations are equivalent to:
+//!
+//! ```c
+//! #include <stdbool.h>
+//! #include <stddef.h>
+//! #include <stdint.h>
+//!
+//! typedef struct RawOcidV0 {
+//!     uint8_t version;
+//!     uint8_t size[6];
+//!     uint8_t hash[32];
+//! } RawOcidV0;
+//!
+//! bool ocid_v0_new(const uint8_t *ptr, size_t len, RawOcidV0 *out);
+//! bool ocid_v0_from_bytes(const uint8_t *ptr, size_t len, RawOcidV0 *out);
+//! void ocid_v0_encode_base64(const RawOcidV0 *id, uint8_t out[52]);
+//! ```
+//!
+//! [`RawOcidV0`]: ../v0/struct.RawOcidV0.html
+//! [`cbindgen`]:  https://github.com/eqrion/cbindgen
+
+use core::slice;
+
+use crate::{
+    v0::{RawOcidV0, BASE64_LEN, LEN},
+    OcidV0,
+};
+
+/// Returns the bytes at `ptr` as a slice, allowing `ptr` to be null if `len`
+/// is 0.
+#[inline]
+unsafe fn bytes<'a>(ptr: *const u8, len: usize) -> Option<&'a [u8]> {
+    if len == 0 {
+        Some(&[])
+    } else if ptr.is_null() {
+        None
+    } else {
+        Some(slice::from_raw_parts(ptr, len))
+    }
+}
+
+/// Generates an ID by hashing the `len` bytes at `ptr` and writes it to `out`.
+///
+/// Returns `false` without writing to `out` if the content is larger than
+/// [`OcidV0::MAX_SIZE`](../struct.OcidV0.html#associatedconstant.MAX_SIZE) or
+/// a pointer is null.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reading `len` bytes, unless `len` is 0. `out` must
+/// be valid for writing a `RawOcidV0`.
+#[no_mangle]
+pub unsafe extern "C" fn ocid_v0_new(
+    ptr: *const u8,
+    len: usize,
+    out: *mut RawOcidV0,
+) -> bool {
+    match (bytes(ptr, len).and_then(OcidV0::new), out.is_null()) {
+        (Some(id), false) => {
+            out.write(id.into_raw());
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Writes the ID stored in the `len` bytes at `ptr` to `out`.
+///
+/// Returns `false` without writing to `out` if `len` is not 39, the ID is not
+/// version 0, or a pointer is null.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reading `len` bytes, unless `len` is 0. `out` must
+/// be valid for writing a `RawOcidV0`.
+#[no_mangle]
+pub unsafe extern "C" fn ocid_v0_from_bytes(
+    ptr: *const u8,
+    len: usize,
+    out: *mut RawOcidV0,
+) -> bool {
+    if len != LEN || ptr.is_null() || out.is_null() {
+        return false;
+    }
+
+    let mut raw = [0; LEN];
+    raw.copy_from_slice(slice::from_raw_parts(ptr, len));
+
+    match OcidV0::from_bytes(raw) {
+        Some(id) => {
+            out.write(id.into_raw());
+            true
+        }
+        None => false,
+    }
+}
+
+/// Writes the 52-byte [Base64] encoding of `id` to `out`.
+///
+/// The output is not null-terminated. Nothing is written if a pointer is null.
+///
+/// # Safety
+///
+/// `id` must be valid for reading a `RawOcidV0`. `out` must be valid for
+/// writing 52 bytes.
+///
+/// [Base64]: https://en.wikipedia.org/wiki/Base64
+#[no_mangle]
+pub unsafe extern "C" fn ocid_v0_encode_base64(
+    id: *const RawOcidV0,
+    out: *mut [u8; BASE64_LEN],
+) {
+    if let (Some(id), Some(out)) = (id.as_ref(), out.as_mut()) {
+        id.encode_base64(out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr;
+
+    #[test]
+    fn new() {
+        let content = b"hello, world";
+        let mut out = RawOcidV0::default();
+
+        let ok =
+            unsafe { ocid_v0_new(content.as_ptr(), content.len(), &mut out) };
+        assert!(ok);
+        assert_eq!(out, OcidV0::new(content).unwrap().into_raw());
+
+        let ok = unsafe { ocid_v0_new(ptr::null(), 0, &mut out) };
+        assert!(ok);
+        assert_eq!(out, OcidV0::new(b"").unwrap().into_raw());
+
+        assert!(!unsafe { ocid_v0_new(ptr::null(), 1, &mut out) });
+        assert!(!unsafe { ocid_v0_new(content.as_ptr(), 1, ptr::null_mut()) });
+    }
+
+    #[test]
+    fn from_bytes() {
+        let id = OcidV0::new(b"hello, world").unwrap();
+        let mut bytes = id.into_bytes();
+        let mut out = RawOcidV0::default();
+
+        let ok = unsafe { ocid_v0_from_bytes(bytes.as_ptr(), LEN, &mut out) };
+        assert!(ok);
+        assert_eq!(out, id.into_raw());
+
+        let mut out = RawOcidV0::default();
+        assert!(!unsafe {
+            ocid_v0_from_bytes(bytes.as_ptr(), LEN - 1, &mut out)
+        });
+
+        bytes[0] = 1;
+        assert!(!unsafe { ocid_v0_from_bytes(bytes.as_ptr(), LEN, &mut out) });
+        assert_eq!(out, RawOcidV0::default());
+    }
+
+    #[test]
+    fn encode_base64() {
+        let id = OcidV0::new(b"hello, world").unwrap();
+        let mut out = [0; BASE64_LEN];
+
+        unsafe { ocid_v0_encode_base64(id.as_raw(), &mut out) };
+        assert_eq!(&out[..], id.to_string().as_bytes());
+    }
+}
@@ -76,16 +76,55 @@
 //! [UTF-8]:                 https://en.wikipedia.org/wiki/UTF-8
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(feature = "nightly-simd", feature(portable_simd))]
 #![cfg_attr(not(test), no_std)]
 
-use core::fmt;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
+
+use core::{cmp, fmt};
 
 pub mod enc;
 pub mod v0;
 
+mod error;
+
+#[cfg(feature = "clap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clap")))]
+pub mod clap;
+
+#[cfg(feature = "ffi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
+pub mod ffi;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+
+#[cfg(feature = "test-vectors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-vectors")))]
+pub mod test_vectors;
+
+#[doc(inline)]
+pub use enc::base64::ALPHABET;
+pub use error::OcidError;
 #[doc(inline)]
 pub use v0::OcidV0;
 
+/// Returns the version of the raw ID at the start of `bytes`, without
+/// constructing it.
+///
+/// Every ID version stores its version number in its first byte. This allows
+/// for determining how to parse the rest of `bytes`. Returns `None` if `bytes`
+/// is empty.
+#[inline]
+pub fn version_of(bytes: &[u8]) -> Option<u8> {
+    bytes.first().copied()
+}
+
 /// Ocean Content ID.
 #[derive(Clone, Copy)]
 #[non_exhaustive]
@@ -120,6 +159,70 @@ impl From<OcidV0> for Ocid {
     }
 }
 
+impl PartialEq for Ocid {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes().as_ref() == other.to_bytes().as_ref()
+    }
+}
+
+impl Eq for Ocid {}
+
+impl PartialOrd for Ocid {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ocid {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        // The version number leads the bytes, so IDs are ordered by version and
+        // then by their version-specific body.
+        self.to_bytes().as_ref().cmp(other.to_bytes().as_ref())
+    }
+}
+
+impl PartialEq<OcidV0> for Ocid {
+    #[inline]
+    fn eq(&self, other: &OcidV0) -> bool {
+        // IDs of any other version are never equal to an `OcidV0`.
+        match self {
+            Ocid::V0 { size, hash } => {
+                size == other.size_bytes() && hash == other.hash()
+            }
+        }
+    }
+}
+
+impl PartialEq<Ocid> for OcidV0 {
+    #[inline]
+    fn eq(&self, other: &Ocid) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<OcidV0> for Ocid {
+    #[inline]
+    fn partial_cmp(&self, other: &OcidV0) -> Option<cmp::Ordering> {
+        // IDs of any other version are ordered by their version number, which
+        // is always greater than 0.
+        match *self {
+            Ocid::V0 { size, hash } => {
+                Some(OcidV0::from_parts(size, hash).cmp(other))
+            }
+        }
+    }
+}
+
+impl PartialOrd<Ocid> for OcidV0 {
+    #[inline]
+    fn partial_cmp(&self, other: &Ocid) -> Option<cmp::Ordering> {
+        other.partial_cmp(self).map(cmp::Ordering::reverse)
+    }
+}
+
 impl fmt::Debug for Ocid {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -145,6 +248,125 @@ impl fmt::Display for Ocid {
 }
 
 impl Ocid {
+    /// Creates an ID from its `version` and the raw bytes of its `body`, i.e.
+    /// all bytes after the version.
+    ///
+    /// This is useful when the version and body are stored separately, such as
+    /// in different fields of a message. Returns an error if `version` is
+    /// unknown or if `body` has the wrong length for it.
+    #[inline]
+    pub fn from_version_body(
+        version: u8,
+        body: &[u8],
+    ) -> Result<Ocid, OcidError> {
+        match version {
+            0 => {
+                let expected = v0::LEN - 1;
+                if body.len() != expected {
+                    return Err(OcidError::WrongBodyLen {
+                        version,
+                        expected,
+                        found: body.len(),
+                    });
+                }
+
+                let mut size = [0; 6];
+                let mut hash = [0; 32];
+                size.copy_from_slice(&body[..6]);
+                hash.copy_from_slice(&body[6..]);
+                Ok(Ocid::V0 { size, hash })
+            }
+            _ => Err(OcidError::UnknownVersion(version)),
+        }
+    }
+
+    /// Returns the ID as an [`OcidV0`] if it is version 0.
+    ///
+    /// [`OcidV0`]: struct.OcidV0.html
+    #[inline]
+    pub fn as_v0(&self) -> Option<OcidV0> {
+        match *self {
+            Ocid::V0 { size, hash } => Some(OcidV0::from_parts(size, hash)),
+        }
+    }
+
+    /// Converts the ID into an [`OcidV0`] if it is version 0, or returns
+    /// `self` otherwise.
+    ///
+    /// [`OcidV0`]: struct.OcidV0.html
+    #[inline]
+    pub fn into_v0(self) -> Result<OcidV0, Ocid> {
+        match self {
+            Ocid::V0 { size, hash } => Ok(OcidV0::from_parts(size, hash)),
+        }
+    }
+
+    /// Converts the ID into an [`OcidV0`], for code that has already checked
+    /// that it is version 0.
+    ///
+    /// This avoids handling a [`Result`] that is known to be `Ok`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ID is not version 0. Calling this on another version is a
+    /// logic bug.
+    ///
+    /// [`OcidV0`]: struct.OcidV0.html
+    /// [`Result`]: https://doc.rust-lang.org/std/result/enum.Result.html
+    #[inline]
+    pub fn expect_v0(self) -> OcidV0 {
+        match self.into_v0() {
+            Ok(id) => id,
+            Err(id) => panic!(
+                "expected OCID version 0 but found version {}",
+                id.version_const(),
+            ),
+        }
+    }
+
+    /// Returns the ID version.
+    ///
+    /// This can be used in const contexts.
+    #[inline]
+    pub const fn version_const(&self) -> u8 {
+        match self {
+            Ocid::V0 { .. } => 0,
+        }
+    }
+
+    /// Returns the canonical bytes of the ID: the version number followed by
+    /// the version-specific body.
+    ///
+    /// For version 0, this is the same as [`OcidV0::as_bytes`].
+    ///
+    /// [`OcidV0::as_bytes`]: struct.OcidV0.html#method.as_bytes
+    #[inline]
+    pub fn to_bytes(&self) -> impl AsRef<[u8]> {
+        match *self {
+            Ocid::V0 { size, hash } => {
+                OcidV0::from_parts(size, hash).into_bytes()
+            }
+        }
+    }
+
+    /// Returns the number of bytes in the ID, including the version number.
+    #[inline]
+    pub fn byte_len(&self) -> usize {
+        match self {
+            Ocid::V0 { .. } => v0::LEN,
+        }
+    }
+
+    /// Returns the number of characters in the [Base64] encoding of the ID.
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    #[inline]
+    pub fn base64_len(&self) -> usize {
+        match self {
+            Ocid::V0 { .. } => v0::BASE64_LEN,
+        }
+    }
+
     /// Returns the result of calling `f` on the [Base64] encoding of the ID.
     ///
     /// The string passed into `f` is temporarily stack-allocated.
@@ -162,3 +384,156 @@ impl Ocid {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eq_v0() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..64 {
+            let a = OcidV0::rand(&mut rng);
+            let b = OcidV0::rand(&mut rng);
+
+            assert_eq!(Ocid::from(a), a);
+            assert_eq!(a, Ocid::from(a));
+
+            assert_eq!(Ocid::from(a) == b, a == b);
+            assert_eq!(b == Ocid::from(a), a == b);
+        }
+    }
+
+    #[test]
+    fn cmp_v0() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..64 {
+            let a = OcidV0::rand(&mut rng);
+            let b = OcidV0::rand(&mut rng);
+
+            assert_eq!(Ocid::from(a).partial_cmp(&b), Some(a.cmp(&b)));
+            assert_eq!(a.partial_cmp(&Ocid::from(b)), Some(a.cmp(&b)));
+            assert_eq!(
+                Ocid::from(a).partial_cmp(&a),
+                Some(cmp::Ordering::Equal),
+            );
+        }
+    }
+
+    #[test]
+    fn cmp() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..64 {
+            let a = OcidV0::rand(&mut rng);
+            let b = OcidV0::rand(&mut rng);
+
+            assert_eq!(Ocid::from(a).cmp(&Ocid::from(b)), a.cmp(&b));
+            assert_eq!(Ocid::from(a) == Ocid::from(b), a == b);
+            assert_eq!(Ocid::from(a), Ocid::from(a));
+        }
+    }
+
+    #[test]
+    fn into_v0() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..64 {
+            let id = OcidV0::rand(&mut rng);
+            let ocid = Ocid::from(id);
+
+            assert_eq!(ocid.as_v0(), Some(id));
+            assert_eq!(ocid.into_v0().ok(), Some(id));
+            assert_eq!(ocid.expect_v0(), id);
+            assert_eq!(id.into_ocid(), ocid);
+        }
+    }
+
+    #[test]
+    fn alphabet() {
+        let expected = concat!(
+            "-0123456789",
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+            "_abcdefghijklmnopqrstuvwxyz",
+        );
+        assert_eq!(&crate::ALPHABET[..], expected.as_bytes());
+        assert!(crate::ALPHABET.windows(2).all(|w| w[0] < w[1]));
+
+        let id = OcidV0::rand(rand_core::OsRng);
+        id.with_base64(|b64| {
+            assert!(b64.bytes().all(|c| crate::ALPHABET.contains(&c)));
+        });
+    }
+
+    #[test]
+    fn version_of() {
+        let id = OcidV0::rand(rand_core::OsRng);
+
+        assert_eq!(crate::version_of(id.as_bytes()), Some(0));
+        assert_eq!(crate::version_of(&[3, 1, 2]), Some(3));
+        assert_eq!(crate::version_of(&[]), None);
+    }
+
+    #[test]
+    fn from_version_body() {
+        let id = OcidV0::rand(rand_core::OsRng);
+        let body = id.body();
+
+        assert_eq!(Ocid::from_version_body(0, body), Ok(Ocid::from(id)));
+        assert_eq!(
+            Ocid::from_version_body(0, &body[1..]),
+            Err(OcidError::WrongBodyLen {
+                version: 0,
+                expected: 38,
+                found: 37,
+            }),
+        );
+        assert_eq!(
+            Ocid::from_version_body(1, body),
+            Err(OcidError::UnknownVersion(1)),
+        );
+    }
+
+    #[test]
+    fn version_const() {
+        const OCID: Ocid = Ocid::V0 {
+            size: [0; 6],
+            hash: [0; 32],
+        };
+        const VERSION: u8 = OCID.version_const();
+
+        assert_eq!(VERSION, 0);
+        assert_eq!(OCID.to_bytes().as_ref()[0], VERSION);
+    }
+
+    #[test]
+    fn to_bytes() {
+        let id = OcidV0::rand(rand_core::OsRng);
+        let ocid = Ocid::from(id);
+
+        assert_eq!(ocid.to_bytes().as_ref(), id.as_bytes());
+    }
+
+    #[test]
+    fn display() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..64 {
+            let id = OcidV0::rand(&mut rng);
+            let b64 = Ocid::from(id).to_string();
+
+            assert_eq!(b64, id.to_string());
+            assert_eq!(b64.parse::<OcidV0>(), Ok(id));
+        }
+    }
+
+    #[test]
+    fn lens() {
+        let ocid = Ocid::from(OcidV0::rand(rand_core::OsRng));
+
+        assert_eq!(ocid.byte_len(), ocid.to_bytes().as_ref().len());
+        assert_eq!(ocid.base64_len(), ocid.with_base64(|b64| b64.len()));
+    }
+}
@@ -0,0 +1,123 @@
+//! [`serde`] helpers for alternative representations of IDs.
+//!
+//! [`serde`]: https://docs.rs/serde/1
+
+/// (De)serializes an `OcidV0` as its 39 raw bytes in all formats, for use
+/// with `#[serde(with = "ocid::serde::bytes")]`.
+///
+/// The bytes are written as a fixed-size tuple, which is a sequence of 39
+/// numbers in human-readable formats like JSON. This suits protocols that
+/// expect numeric arrays, but is about 3 times larger in JSON than the
+/// 52-character [Base64] string used by default and cannot be compared or
+/// copied by eye.
+///
+/// ```
+/// # use ocid::OcidV0;
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Package {
+///     #[serde(with = "ocid::serde::bytes")]
+///     id: OcidV0,
+/// }
+/// ```
+///
+/// Deserialization fails if the version is not 0.
+///
+/// [Base64]: https://en.wikipedia.org/wiki/Base64
+pub mod bytes {
+    use core::fmt;
+
+    use serde::{
+        de::{self, SeqAccess, Visitor},
+        ser::SerializeTuple,
+        Deserializer, Serializer,
+    };
+
+    use crate::v0::{OcidV0, LEN};
+
+    /// Serializes `id` as a tuple of its 39 bytes.
+    pub fn serialize<S: Serializer>(
+        id: &OcidV0,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(LEN)?;
+        for byte in id.as_bytes() {
+            tuple.serialize_element(byte)?;
+        }
+        tuple.end()
+    }
+
+    /// Deserializes an ID from a tuple of its 39 bytes.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OcidV0, D::Error> {
+        struct BytesVisitor;
+
+        impl<'de> Visitor<'de> for BytesVisitor {
+            type Value = OcidV0;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{} bytes of an OCID version 0", LEN)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<OcidV0, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut bytes = [0u8; LEN];
+                for (i, byte) in bytes.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+
+                OcidV0::from_bytes(bytes).ok_or_else(|| {
+                    de::Error::invalid_value(
+                        de::Unexpected::Unsigned(bytes[0].into()),
+                        &"version 0",
+                    )
+                })
+            }
+        }
+
+        deserializer.deserialize_tuple(LEN, BytesVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::OcidV0;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Package {
+        #[serde(with = "super::bytes")]
+        id: OcidV0,
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let id = OcidV0::new(b"hello, world").unwrap();
+        let package = Package { id };
+
+        let json = serde_json::to_string(&package).unwrap();
+        let array = serde_json::to_string(id.as_bytes().as_ref()).unwrap();
+        assert_eq!(json, format!(r#"{{"id":{}}}"#, array));
+
+        let parsed: Package = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, package);
+    }
+
+    #[test]
+    fn bytes_invalid() {
+        let id = OcidV0::new(b"hello, world").unwrap();
+
+        let mut bytes = id.as_bytes().to_vec();
+        bytes[0] = 1;
+        let json = serde_json::json!({ "id": bytes }).to_string();
+        assert!(serde_json::from_str::<Package>(&json).is_err());
+
+        bytes[0] = 0;
+        bytes.pop();
+        let json = serde_json::json!({ "id": bytes }).to_string();
+        assert!(serde_json::from_str::<Package>(&json).is_err());
+    }
+}
@@ -0,0 +1,77 @@
+//! Known content-to-ID mappings for testing integrations.
+//!
+//! Each vector pairs content with the [`OcidV0`] it is expected to produce,
+//! as verified against [BLAKE3]. The hashes of [`SINGLE_BYTE`] and of the
+//! content of [`PATTERN_1MIB`] follow the input pattern of the
+//! [official BLAKE3 test vectors].
+//!
+//! [`OcidV0`]: ../struct.OcidV0.html
+//!
+//! [BLAKE3]:                        https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+//! [official BLAKE3 test vectors]: https://github.com/BLAKE3-team/BLAKE3/blob/master/test_vectors/test_vectors.json
+
+use crate::OcidV0;
+
+/// Empty content.
+///
+/// The ID is the same as
+/// [`OcidV0::EMPTY_CONTENT`](../struct.OcidV0.html#associatedconstant.EMPTY_CONTENT).
+pub const EMPTY: (&[u8], OcidV0) = (b"", OcidV0::EMPTY_CONTENT);
+
+/// A single zero byte.
+pub const SINGLE_BYTE: (&[u8], OcidV0) = (&[0], SINGLE_BYTE_ID);
+
+#[rustfmt::skip]
+const SINGLE_BYTE_ID: OcidV0 = OcidV0::from_parts([0, 0, 0, 0, 0, 1], [
+    0x2D, 0x3A, 0xDE, 0xDF, 0xF1, 0x1B, 0x61, 0xF1,
+    0x4C, 0x88, 0x6E, 0x35, 0xAF, 0xA0, 0x36, 0x73,
+    0x6D, 0xCD, 0x87, 0xA7, 0x4D, 0x27, 0xB5, 0xC1,
+    0x51, 0x02, 0x25, 0xD0, 0xF5, 0x92, 0xE2, 0x13,
+]);
+
+/// The ASCII text `hello, world`.
+pub const HELLO_WORLD: (&[u8], OcidV0) = (b"hello, world", HELLO_WORLD_ID);
+
+#[rustfmt::skip]
+const HELLO_WORLD_ID: OcidV0 = OcidV0::from_parts([0, 0, 0, 0, 0, 12], [
+    0xA1, 0xA5, 0x58, 0x87, 0x53, 0x53, 0x97, 0xBF,
+    0x46, 0x19, 0x02, 0x49, 0x1C, 0x87, 0x79, 0x18,
+    0x8A, 0x5D, 0xD1, 0xF8, 0xC3, 0x95, 0x1B, 0x3D,
+    0x9C, 0xF6, 0xEC, 0xBA, 0x19, 0x4E, 0x87, 0xB0,
+]);
+
+/// The number of bytes in the content of [`PATTERN_1MIB`], which is 1 MiB.
+pub const PATTERN_1MIB_LEN: usize = 1 << 20;
+
+/// The ID of 1 MiB of content where the byte at index `i` is `i % 251`.
+///
+/// The content is produced by [`pattern_1mib`].
+#[rustfmt::skip]
+pub const PATTERN_1MIB: OcidV0 = OcidV0::from_parts([0, 0, 0, 0x10, 0, 0], [
+    0x74, 0xCB, 0x44, 0x1F, 0xD0, 0x87, 0x76, 0x4C,
+    0xA9, 0xC3, 0x69, 0x4D, 0xA7, 0x42, 0xEB, 0xE3,
+    0x0C, 0xBE, 0xB3, 0x06, 0x0A, 0x17, 0x00, 0x9C,
+    0xA8, 0x18, 0x25, 0xC7, 0xA8, 0xD1, 0x03, 0x43,
+]);
+
+/// Returns an iterator over the content of [`PATTERN_1MIB`].
+#[inline]
+pub fn pattern_1mib() -> impl Iterator<Item = u8> {
+    (0..PATTERN_1MIB_LEN).map(|i| (i % 251) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_new() {
+        for &(content, id) in &[EMPTY, SINGLE_BYTE, HELLO_WORLD] {
+            assert_eq!(OcidV0::new(content), Some(id));
+        }
+
+        let pattern: Vec<u8> = pattern_1mib().collect();
+        assert_eq!(pattern.len(), PATTERN_1MIB_LEN);
+        assert_eq!(OcidV0::new(&pattern), Some(PATTERN_1MIB));
+    }
+}
@@ -0,0 +1,104 @@
+use digest::{
+    consts::U32, FixedOutput, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+use super::{OcidV0, OcidV0Hasher};
+
+/// A [`digest`] adapter for hashing content into an [`OcidV0`].
+///
+/// The [`FixedOutput`] of this type is only the 32-byte [BLAKE3] hash of the
+/// content. The content size is tracked out-of-band from the digest output and
+/// can be retrieved along with the hash via [`ocid`].
+///
+/// [`digest`]:      https://docs.rs/digest/0.10
+/// [`FixedOutput`]: https://docs.rs/digest/0.10/digest/trait.FixedOutput.html
+/// [`OcidV0`]:      struct.OcidV0.html
+/// [`ocid`]:        #method.ocid
+///
+/// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+#[derive(Clone)]
+pub struct OcidV0Digest {
+    hasher: OcidV0Hasher,
+}
+
+impl Default for OcidV0Digest {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OutputSizeUser for OcidV0Digest {
+    type OutputSize = U32;
+}
+
+impl Update for OcidV0Digest {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update_unbounded(data);
+    }
+}
+
+impl FixedOutput for OcidV0Digest {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&self.hasher.hash());
+    }
+}
+
+impl Reset for OcidV0Digest {
+    #[inline]
+    fn reset(&mut self) {
+        self.hasher.reset();
+    }
+}
+
+impl HashMarker for OcidV0Digest {}
+
+impl OcidV0Digest {
+    /// Creates a new digest with no content.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            hasher: OcidV0Hasher::new(),
+        }
+    }
+
+    /// Returns the number of bytes of content hashed so far.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.hasher.size()
+    }
+
+    /// Returns the ID of the content hashed so far.
+    ///
+    /// Returns `None` if the content is larger than
+    /// [`OcidV0::MAX_SIZE`](struct.OcidV0.html#associatedconstant.MAX_SIZE).
+    #[inline]
+    pub fn ocid(&self) -> Option<OcidV0> {
+        self.hasher.finalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use digest::Digest;
+
+    #[test]
+    fn matches_new() {
+        let content = b"hello, world";
+        let id = OcidV0::new(content).unwrap();
+
+        let hash = OcidV0Digest::digest(content);
+        assert_eq!(hash.as_slice(), id.hash());
+
+        let mut digest = OcidV0Digest::new();
+        Digest::update(&mut digest, &content[..5]);
+        Digest::update(&mut digest, &content[5..]);
+        assert_eq!(digest.ocid(), Some(id));
+
+        Digest::reset(&mut digest);
+        assert_eq!(digest.ocid(), OcidV0::new(b""));
+    }
+}
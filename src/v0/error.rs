@@ -0,0 +1,116 @@
+use core::fmt;
+
+use super::LEN;
+use crate::enc::{base64::DecodeError, hex};
+
+/// An error returned when parsing an [`OcidV0`](struct.OcidV0.html) fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The input ended after the given number of bytes, before a whole ID
+    /// could be read.
+    Truncated(usize),
+    /// The raw ID has the given length in bytes, which is not 39.
+    InvalidLength(usize),
+    /// The ID has the given version, which is not 0.
+    UnexpectedVersion(u8),
+    /// The checksum character of the ID does not match its [Base64] encoding.
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    ChecksumMismatch {
+        /// The checksum character computed from the encoding.
+        expected: u8,
+        /// The checksum character found in the input.
+        found: u8,
+    },
+    /// The ID is not valid [Base64].
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    Base64(DecodeError),
+    /// The ID is not valid [hexadecimal].
+    ///
+    /// [hexadecimal]: https://en.wikipedia.org/wiki/Hexadecimal
+    Hex(hex::DecodeError),
+    /// The ID has a size of 0 where a non-empty ID was required.
+    Empty,
+}
+
+impl From<DecodeError> for ParseError {
+    #[inline]
+    fn from(error: DecodeError) -> Self {
+        match error {
+            DecodeError::UnexpectedVersion(version) => {
+                ParseError::UnexpectedVersion(version)
+            }
+            error => ParseError::Base64(error),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::Truncated(len) => write!(
+                f,
+                "expected {} bytes for OCID version 0 but input ended after {}",
+                LEN, len,
+            ),
+            ParseError::InvalidLength(len) => write!(
+                f,
+                "expected {} bytes for OCID version 0 but found {}",
+                LEN, len,
+            ),
+            ParseError::UnexpectedVersion(version) => write!(
+                f,
+                "expected OCID version 0 but found version {}",
+                version,
+            ),
+            ParseError::ChecksumMismatch { expected, found } => write!(
+                f,
+                "expected checksum {:?} but found {:?}",
+                expected as char, found as char,
+            ),
+            ParseError::Base64(error) => error.fmt(f),
+            ParseError::Hex(error) => error.fmt(f),
+            ParseError::Empty => f.write_str("expected non-empty OCID"),
+        }
+    }
+}
+
+/// Has no [`source`], since the `Display` of [`Base64`] and [`Hex`] errors
+/// already includes the message of the underlying error.
+///
+/// [`source`]: https://doc.rust-lang.org/std/error/trait.Error.html#method.source
+/// [`Base64`]: #variant.Base64
+/// [`Hex`]:    #variant.Hex
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ParseError {}
+
+/// An error returned when parsing an [`OcidV0`](struct.OcidV0.html) from a
+/// line of text fails.
+///
+/// This is yielded by
+/// [`OcidV0::parse_lines`](struct.OcidV0.html#method.parse_lines).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LineError {
+    /// The line number of the ID, starting at 1.
+    pub line: usize,
+    /// The reason the ID could not be parsed.
+    pub error: ParseError,
+}
+
+impl fmt::Display for LineError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for LineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
@@ -0,0 +1,349 @@
+use core::fmt;
+
+#[cfg(feature = "std")]
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+#[cfg(feature = "std")]
+use super::io::read_chunks;
+use super::OcidV0;
+
+/// An incremental hasher for generating an [`OcidV0`] from content that
+/// arrives in pieces.
+///
+/// Content is hashed using [BLAKE3] and its size is tracked alongside the hash.
+/// Feeding the pieces `a` then `b` produces the same ID as hashing their
+/// concatenation:
+///
+/// ```
+/// use ocid::v0::OcidV0Hasher;
+/// # use ocid::OcidV0;
+///
+/// let mut hasher = OcidV0Hasher::new();
+/// hasher.update(b"hello, ")?.update(b"world")?;
+///
+/// assert_eq!(hasher.finalize(), OcidV0::new(b"hello, world"));
+/// # Ok::<(), ocid::v0::SizeExceeded>(())
+/// ```
+///
+/// # Expected Size
+///
+/// When verifying content of a known size, such as a download, a hasher
+/// created with [`with_expected_size`] rejects content beyond that size in
+/// [`update`] without hashing it. This allows aborting early on an over-long
+/// stream.
+///
+/// [`with_expected_size`]: #method.with_expected_size
+/// [`update`]:             #method.update
+///
+/// [`OcidV0`]: struct.OcidV0.html
+/// [BLAKE3]:   https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+#[derive(Clone)]
+pub struct OcidV0Hasher {
+    hasher: blake3::Hasher,
+    size: u64,
+    expected_size: Option<u64>,
+}
+
+impl Default for OcidV0Hasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OcidV0Hasher {
+    /// Creates a new hasher with no content.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            hasher: blake3::Hasher::new(),
+            size: 0,
+            expected_size: None,
+        }
+    }
+
+    /// Creates a new hasher with no content that only accepts content of
+    /// exactly `size` bytes.
+    #[inline]
+    pub fn with_expected_size(size: u64) -> Self {
+        Self {
+            expected_size: Some(size),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the size passed to
+    /// [`with_expected_size`](#method.with_expected_size), if any.
+    #[inline]
+    pub fn expected_size(&self) -> Option<u64> {
+        self.expected_size
+    }
+
+    /// Adds `content` to the end of the content hashed so far.
+    ///
+    /// Returns an error without hashing `content` if it would make the content
+    /// larger than the [expected size](#expected-size).
+    #[inline]
+    pub fn update(
+        &mut self,
+        content: &[u8],
+    ) -> Result<&mut Self, SizeExceeded> {
+        if let Some(expected) = self.expected_size {
+            let found = self.size.saturating_add(content.len() as u64);
+            if found > expected {
+                return Err(SizeExceeded { expected, found });
+            }
+        }
+
+        self.update_unbounded(content);
+        Ok(self)
+    }
+
+    /// Adds `content` regardless of the expected size, for hashers that are
+    /// known to not have one.
+    #[inline]
+    pub(super) fn update_unbounded(&mut self, content: &[u8]) {
+        self.hasher.update(content);
+        self.size = self.size.saturating_add(content.len() as u64);
+    }
+
+    /// Clears all content hashed so far.
+    ///
+    /// The expected size, if any, is kept.
+    #[inline]
+    pub fn reset(&mut self) -> &mut Self {
+        self.hasher.reset();
+        self.size = 0;
+        self
+    }
+
+    /// Returns the number of bytes of content hashed so far.
+    #[inline]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Returns the [BLAKE3] hash of the content hashed so far.
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[inline]
+    pub fn hash(&self) -> [u8; 32] {
+        self.hasher.finalize().into()
+    }
+
+    /// Returns the ID of the content hashed so far.
+    ///
+    /// This does not consume the hasher, so more content can be added after.
+    /// Returns `None` if the content is larger than
+    /// [`OcidV0::MAX_SIZE`](struct.OcidV0.html#associatedconstant.MAX_SIZE)
+    /// or if its size is not the [expected size](#expected-size).
+    #[inline]
+    pub fn finalize(&self) -> Option<OcidV0> {
+        match self.expected_size {
+            Some(expected) if expected != self.size => None,
+            _ => OcidV0::from_parts_u64(self.size, self.hash()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "blake3"))))]
+impl OcidV0Hasher {
+    /// Adds the path and content of the file at `path` to the content hashed
+    /// so far, such as for computing one cache key over several build
+    /// artifacts.
+    ///
+    /// The file is hashed the same way as by
+    /// [`OcidV0::from_dir`](struct.OcidV0.html#method.from_dir), with `path`
+    /// as given. Only the size of the file content is added to
+    /// [`size`](#method.size).
+    ///
+    /// # Reproducibility
+    ///
+    /// Because both paths and contents are hashed in order, callers must feed
+    /// files in a deterministic order, such as sorted by path, using the same
+    /// relative paths each time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidData`] if `path` is not UTF-8, the
+    /// file changed size while being read, or the file would make the content
+    /// larger than the [expected size](#expected-size). The hasher must not be
+    /// used after an error other than for a path that is not UTF-8 or a file
+    /// that could not be opened.
+    ///
+    /// [`InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    pub fn update_file<P: AsRef<Path>>(&mut self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        let name = path.to_str().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                std::format!("path {:?} is not UTF-8", path),
+            )
+        })?;
+        self.update_file_named(name, File::open(path)?)
+    }
+
+    /// Adds `file` to the content hashed so far, with its path as `name`.
+    pub(super) fn update_file_named(
+        &mut self,
+        name: &str,
+        file: File,
+    ) -> io::Result<()> {
+        let len = file.metadata()?.len();
+
+        if let Some(expected) = self.expected_size {
+            let found = self.size.saturating_add(len);
+            if found > expected {
+                let error = SizeExceeded { expected, found };
+                return Err(io::Error::new(io::ErrorKind::InvalidData, error));
+            }
+        }
+
+        self.hasher.update(&(name.len() as u64).to_be_bytes());
+        self.hasher.update(name.as_bytes());
+        self.hasher.update(&len.to_be_bytes());
+
+        // Read past the expected length to detect a file that grew.
+        let mut read = 0u64;
+        read_chunks(file.take(len.saturating_add(1)), |chunk| {
+            self.update_unbounded(chunk);
+            read += chunk.len() as u64;
+        })?;
+
+        if read != len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                std::format!("{:?} changed size while being read", name),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when content given to an
+/// [`OcidV0Hasher`](struct.OcidV0Hasher.html) exceeds its expected size.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SizeExceeded {
+    /// The expected size of the content.
+    pub expected: u64,
+    /// The size the content would have had.
+    pub found: u64,
+}
+
+impl fmt::Display for SizeExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected {} bytes of content but found at least {}",
+            self.expected, self.found,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for SizeExceeded {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_new() {
+        let content = b"hello, world";
+
+        let mut hasher = OcidV0Hasher::new();
+        assert_eq!(hasher.finalize(), OcidV0::new(b""));
+
+        for chunk in content.chunks(5) {
+            hasher.update(chunk).unwrap();
+        }
+        assert_eq!(hasher.size(), content.len() as u64);
+        assert_eq!(hasher.finalize(), OcidV0::new(content));
+
+        hasher.reset();
+        assert_eq!(hasher.size(), 0);
+        assert_eq!(hasher.finalize(), OcidV0::new(b""));
+    }
+
+    #[test]
+    fn expected_size() {
+        let content = b"hello, world";
+        let expected = content.len() as u64;
+
+        let mut hasher = OcidV0Hasher::with_expected_size(expected);
+        assert_eq!(hasher.expected_size(), Some(expected));
+
+        hasher.update(&content[..5]).unwrap();
+        assert_eq!(hasher.finalize(), None);
+
+        hasher.update(&content[5..]).unwrap();
+        assert_eq!(hasher.finalize(), OcidV0::new(content));
+
+        // Over-long content is rejected without being hashed.
+        let error = hasher.update(b"!").err();
+        let found = expected + 1;
+        assert_eq!(error, Some(SizeExceeded { expected, found }));
+        assert_eq!(hasher.size(), expected);
+        assert_eq!(hasher.finalize(), OcidV0::new(content));
+
+        hasher.reset();
+        assert_eq!(hasher.expected_size(), Some(expected));
+        assert!(hasher.update(b"hello, world!").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn update_file() {
+        // Include the process ID to not clash with concurrent test runs.
+        let name = format!("ocid-v0-update-file-{}", std::process::id());
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("sub")).unwrap();
+        std::fs::write(dir.join("a.txt"), b"hello").unwrap();
+        std::fs::write(dir.join("sub/b.txt"), b"world").unwrap();
+
+        let mut hasher = OcidV0Hasher::new();
+        for path in &["a.txt", "sub/b.txt"] {
+            let file = File::open(dir.join(path)).unwrap();
+            hasher.update_file_named(path, file).unwrap();
+        }
+
+        let mut absolute = OcidV0Hasher::new();
+        absolute.update_file(dir.join("a.txt")).unwrap();
+
+        let mut limited = OcidV0Hasher::with_expected_size(4);
+        let error = limited.update_file(dir.join("a.txt")).unwrap_err();
+
+        let from_dir = OcidV0::from_dir(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(hasher.size(), 10);
+        assert_eq!(hasher.finalize(), from_dir);
+
+        assert_eq!(absolute.size(), 5);
+        assert_ne!(absolute.finalize(), OcidV0::new(b"hello"));
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(limited.size(), 0);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", target_os = "linux"))]
+    fn update_file_grown() {
+        // Files in `/proc` report a size of 0 but have content, like a file
+        // that grew after its size was read.
+        let file = File::open("/proc/self/stat").unwrap();
+        assert_eq!(file.metadata().unwrap().len(), 0);
+
+        let mut hasher = OcidV0Hasher::new();
+        let error = hasher.update_file_named("stat", file).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
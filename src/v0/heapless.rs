@@ -0,0 +1,38 @@
+use heapless::String;
+
+use super::{OcidV0, BASE64_LEN};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl OcidV0 {
+    /// Returns the [Base64] encoding of the ID as a fixed-capacity
+    /// [`heapless::String`], for an owned string without allocation.
+    ///
+    /// The capacity is exactly [`BASE64_LEN`](#associatedconstant.BASE64_LEN).
+    ///
+    /// [`heapless::String`]: https://docs.rs/heapless/0.8/heapless/struct.String.html
+    /// [Base64]:             https://en.wikipedia.org/wiki/Base64
+    #[inline]
+    pub fn to_heapless(&self) -> String<BASE64_LEN> {
+        let mut buf = [0u8; BASE64_LEN];
+        let mut string = String::new();
+        match string.push_str(self.encode_base64(&mut buf)) {
+            Ok(()) => string,
+            Err(()) => unreachable!("Base64 does not fit in its own length"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_heapless() {
+        let id = OcidV0::rand(rand_core::OsRng);
+
+        let string = id.to_heapless();
+        assert_eq!(string.len(), 52);
+        assert_eq!(string.len(), string.capacity());
+        assert_eq!(string.as_str(), id.to_string());
+    }
+}
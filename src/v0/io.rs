@@ -0,0 +1,565 @@
+use core::mem::MaybeUninit;
+use std::io::{self, BufRead, Write};
+
+#[cfg(any(test, docsrs, feature = "blake3"))]
+use std::{
+    format,
+    fs::{self, File},
+    io::Read,
+    path::{Path, PathBuf},
+    string::String,
+    vec::Vec,
+};
+
+#[cfg(any(test, docsrs, feature = "blake3"))]
+use super::OcidV0Hasher;
+use super::{OcidV0, ParseError, BASE64_LEN, LEN};
+
+/// The number of bytes read from a reader at a time.
+#[cfg(any(test, docsrs, feature = "blake3"))]
+pub(super) const BUF_LEN: usize = 16 * 1024;
+
+/// Calls `f` on each chunk of at most `BUF_LEN` bytes read from `reader`
+/// until EOF.
+#[cfg(any(test, docsrs, feature = "blake3"))]
+pub(super) fn read_chunks<R, F>(mut reader: R, mut f: F) -> io::Result<()>
+where
+    R: Read,
+    F: FnMut(&[u8]),
+{
+    let mut buf = [0u8; BUF_LEN];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return Ok(()),
+            Ok(len) => f(&buf[..len]),
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl OcidV0 {
+    /// Reads exactly one ID from the front of `reader`, leaving the rest of its
+    /// buffer intact.
+    ///
+    /// Returns an error of kind [`UnexpectedEof`] if `reader` ends before 39
+    /// bytes are read, or of kind [`InvalidData`] if the ID does not have
+    /// version 0.
+    ///
+    /// [`UnexpectedEof`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+    /// [`InvalidData`]:   https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    #[inline]
+    pub fn read_one<R: BufRead>(reader: &mut R) -> io::Result<OcidV0> {
+        let mut bytes = [0u8; LEN];
+        reader.read_exact(&mut bytes)?;
+
+        Self::from_wire(&bytes)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Reads one ID prefixed by its length from `reader`, such as from a framed
+    /// protocol.
+    ///
+    /// The prefix is a single byte holding the length of the ID in bytes,
+    /// which must be 39. This is also how 39 is encoded as an unsigned
+    /// [LEB128] varint, so varint-prefixed records are read the same way. The
+    /// 39 bytes of the ID are then read as by [`read_one`](#method.read_one).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`UnexpectedEof`] if `reader` ends early, or
+    /// of kind [`InvalidData`] if the ID does not have version 0. If the prefix
+    /// is not 39, the ID is not read and the error is of kind `InvalidData`
+    /// with a [`ParseError::InvalidLength`] as its inner error.
+    ///
+    /// [LEB128]:                     https://en.wikipedia.org/wiki/LEB128
+    /// [`UnexpectedEof`]:            https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.UnexpectedEof
+    /// [`InvalidData`]:              https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    /// [`ParseError::InvalidLength`]: v0/enum.ParseError.html#variant.InvalidLength
+    pub fn read_length_prefixed<R: io::Read + ?Sized>(
+        reader: &mut R,
+    ) -> io::Result<OcidV0> {
+        let mut len = [0u8; 1];
+        reader.read_exact(&mut len)?;
+
+        let len = usize::from(len[0]);
+        if len != LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                ParseError::InvalidLength(len),
+            ));
+        }
+
+        let mut bytes = [0u8; LEN];
+        reader.read_exact(&mut bytes)?;
+
+        Self::from_wire(&bytes)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Writes the [Base64] encoding of the ID to `writer`.
+    ///
+    /// The encoding is written from a stack buffer, which avoids building a
+    /// `String` when a writer is already available. Use the [`Display`]
+    /// implementation for [`fmt::Write`] destinations.
+    ///
+    /// [`Display`]:    https://doc.rust-lang.org/std/fmt/trait.Display.html
+    /// [`fmt::Write`]: https://doc.rust-lang.org/std/fmt/trait.Write.html
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    #[inline]
+    pub fn encode_base64_to_writer<W: Write + ?Sized>(
+        &self,
+        writer: &mut W,
+    ) -> io::Result<()> {
+        let mut buf = [MaybeUninit::uninit(); BASE64_LEN];
+        writer.write_all(self.encode_base64_uninit(&mut buf).as_bytes())
+    }
+}
+
+#[cfg(any(test, docsrs, feature = "blake3"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "blake3"))))]
+impl OcidV0 {
+    /// Generates an ID by hashing all of the content of `reader` using
+    /// [BLAKE3].
+    ///
+    /// Content is read into a 16 KiB stack buffer until EOF. Returns
+    /// `Ok(None)` if the content is larger than
+    /// [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    ///
+    /// # Tracing
+    ///
+    /// With the `tracing` feature, this runs within a `DEBUG` span with target
+    /// `ocid` and name `hash`. The span records the following fields upon
+    /// completion:
+    ///
+    /// | Field        | Description
+    /// | :----------- | :----------
+    /// | `bytes`      | The number of bytes hashed
+    /// | `elapsed_us` | The time spent hashing, in microseconds
+    /// | `size`       | The size of the resulting ID, if any
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[inline]
+    pub fn from_reader<R: Read>(reader: R) -> io::Result<Option<OcidV0>> {
+        Self::from_reader_with_progress(reader, |_| {})
+    }
+
+    /// Generates an ID by hashing all of the content of `reader` using
+    /// [BLAKE3], calling `progress` with the number of bytes hashed so far
+    /// after each chunk.
+    ///
+    /// Chunks are at most 16 KiB, the size of the internal read buffer, and
+    /// are usually the size returned by each call to [`Read::read`]. As a
+    /// result, `progress` is called at least once per 16 KiB of content. It is
+    /// not called for empty content.
+    ///
+    /// This otherwise behaves the same as [`from_reader`](#method.from_reader),
+    /// including for tracing.
+    ///
+    /// [`Read::read`]: https://doc.rust-lang.org/std/io/trait.Read.html#tymethod.read
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    pub fn from_reader_with_progress<R, F>(
+        reader: R,
+        mut progress: F,
+    ) -> io::Result<Option<OcidV0>>
+    where
+        R: Read,
+        F: FnMut(u64),
+    {
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            target: "ocid",
+            "hash",
+            bytes = tracing::field::Empty,
+            elapsed_us = tracing::field::Empty,
+            size = tracing::field::Empty,
+        );
+        #[cfg(feature = "tracing")]
+        let _enter = span.enter();
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        let mut hasher = OcidV0Hasher::new();
+        read_chunks(reader, |chunk| {
+            hasher.update_unbounded(chunk);
+            progress(hasher.size());
+        })?;
+
+        let id = hasher.finalize();
+
+        #[cfg(feature = "tracing")]
+        {
+            span.record("bytes", hasher.size());
+            span.record("elapsed_us", start.elapsed().as_micros() as u64);
+            if let Some(id) = &id {
+                span.record("size", id.size());
+            }
+        }
+
+        Ok(id)
+    }
+
+    /// Checks whether the content of `reader` matches the ID, reporting how it
+    /// differs if not, such as for diagnosing a failed download.
+    ///
+    /// The size is checked as content is read, and reading stops after the
+    /// first byte past the expected size. Thus content that is too long is
+    /// reported as [`SizeMismatch`] with an `actual` size of one more than
+    /// expected, without reading it to EOF. The hash is only compared if the
+    /// size matches, so [`HashMismatch`] means the content has the right size
+    /// but is wrong.
+    ///
+    /// [`SizeMismatch`]: v0/enum.VerifyOutcome.html#variant.SizeMismatch
+    /// [`HashMismatch`]: v0/enum.VerifyOutcome.html#variant.HashMismatch
+    pub fn verify_reader_detailed<R: Read>(
+        &self,
+        reader: R,
+    ) -> io::Result<VerifyOutcome> {
+        let expected = self.size();
+
+        let mut hasher = OcidV0Hasher::new();
+        read_chunks(reader.take(expected.saturating_add(1)), |chunk| {
+            hasher.update_unbounded(chunk);
+        })?;
+
+        let actual = hasher.size();
+        let outcome = if actual != expected {
+            VerifyOutcome::SizeMismatch { expected, actual }
+        } else if hasher.hash() != *self.hash() {
+            VerifyOutcome::HashMismatch
+        } else {
+            VerifyOutcome::Match
+        };
+        Ok(outcome)
+    }
+
+    /// Generates an ID by hashing the content of the file at `path` using
+    /// [BLAKE3].
+    ///
+    /// See [`from_reader`](#method.from_reader) for details.
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[inline]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<Option<OcidV0>> {
+        Self::from_reader(File::open(path)?)
+    }
+
+    /// Generates an ID by hashing all of standard input using [BLAKE3], such
+    /// as for a command-line tool run as `ocid < file`.
+    ///
+    /// Standard input is locked and read until EOF. Returns `Ok(None)` if the
+    /// content is larger than [`MAX_SIZE`](#associatedconstant.MAX_SIZE). See
+    /// [`from_reader`](#method.from_reader) for details.
+    ///
+    /// ```no_run
+    /// use ocid::OcidV0;
+    ///
+    /// match OcidV0::from_stdin()? {
+    ///     Some(id) => println!("{}", id),
+    ///     None => eprintln!("input is too large"),
+    /// }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[inline]
+    pub fn from_stdin() -> io::Result<Option<OcidV0>> {
+        Self::from_reader(io::stdin().lock())
+    }
+
+    /// Generates an ID by hashing all files within the directory at `path`
+    /// using [BLAKE3], such as for a package tree.
+    ///
+    /// The ID is reproducible across platforms. Its size is the total size of
+    /// all files, and its hash is over each file in turn as:
+    ///
+    /// 1. The length of its relative path as an 8-byte big-endian integer.
+    /// 2. Its relative path, as UTF-8 with components separated by `/`.
+    /// 3. The length of its content as an 8-byte big-endian integer.
+    /// 4. Its content.
+    ///
+    /// Files are ordered by the bytes of their relative paths. Directories only
+    /// contribute through the paths of their files, so empty directories are
+    /// ignored. Metadata, such as file modes and timestamps, is ignored.
+    ///
+    /// Returns an error of kind [`InvalidData`] if a file name is not UTF-8 or
+    /// a file changed size while being read, or of kind [`InvalidInput`] if
+    /// an entry is not a file or directory, such as a symbolic link. Returns
+    /// `Ok(None)` if the total size is larger than
+    /// [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    ///
+    /// This is the same as calling [`OcidV0Hasher::update_file`] from within
+    /// the directory on each relative path in order. Note that because paths
+    /// are hashed, the ID of a directory containing one file is not the same
+    /// as the ID of that file.
+    ///
+    /// [`OcidV0Hasher::update_file`]: v0/struct.OcidV0Hasher.html#method.update_file
+    ///
+    /// [`InvalidData`]:  https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+    /// [`InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    pub fn from_dir<P: AsRef<Path>>(path: P) -> io::Result<Option<OcidV0>> {
+        fn walk(
+            dir: &Path,
+            prefix: &str,
+            files: &mut Vec<(String, PathBuf)>,
+        ) -> io::Result<()> {
+            for entry in fs::read_dir(dir)? {
+                let entry = entry?;
+
+                let name = entry.file_name().into_string().map_err(|name| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("file name {:?} is not UTF-8", name),
+                    )
+                })?;
+                let path = if prefix.is_empty() {
+                    name
+                } else {
+                    format!("{}/{}", prefix, name)
+                };
+
+                let file_type = entry.file_type()?;
+                if file_type.is_dir() {
+                    walk(&entry.path(), &path, files)?;
+                } else if file_type.is_file() {
+                    files.push((path, entry.path()));
+                } else {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("{:?} is not a file or directory", path),
+                    ));
+                }
+            }
+            Ok(())
+        }
+
+        let mut files = Vec::new();
+        walk(path.as_ref(), "", &mut files)?;
+        files.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut hasher = OcidV0Hasher::new();
+        for (path, full_path) in &files {
+            hasher.update_file_named(path, File::open(full_path)?)?;
+        }
+        Ok(hasher.finalize())
+    }
+}
+
+/// The result of
+/// [`OcidV0::verify_reader_detailed`](struct.OcidV0.html#method.verify_reader_detailed).
+#[cfg(any(test, docsrs, feature = "blake3"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "blake3"))))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VerifyOutcome {
+    /// The content matches the ID.
+    Match,
+    /// The content does not have the size of the ID.
+    SizeMismatch {
+        /// The size of the ID.
+        expected: u64,
+        /// The size of the content, or one more than `expected` if the
+        /// content is longer.
+        actual: u64,
+    },
+    /// The content has the size of the ID but not its hash.
+    HashMismatch,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::RngCore;
+
+    #[test]
+    fn read_one() {
+        let mut rng = rand_core::OsRng;
+        let a = OcidV0::rand(&mut rng);
+        let b = OcidV0::rand(&mut rng);
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(a.as_bytes());
+        bytes.extend_from_slice(b.as_bytes());
+        bytes.extend_from_slice(b"rest");
+
+        let mut reader = io::BufReader::new(&bytes[..]);
+        assert_eq!(OcidV0::read_one(&mut reader).unwrap(), a);
+        assert_eq!(OcidV0::read_one(&mut reader).unwrap(), b);
+        assert_eq!(reader.fill_buf().unwrap(), b"rest");
+
+        let error = OcidV0::read_one(&mut reader).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        bytes[0] = 1;
+        let error = OcidV0::read_one(&mut &bytes[..]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn read_length_prefixed() {
+        let mut rng = rand_core::OsRng;
+        let a = OcidV0::rand(&mut rng);
+        let b = OcidV0::rand(&mut rng);
+
+        let mut bytes = Vec::new();
+        for id in &[a, b] {
+            bytes.push(LEN as u8);
+            bytes.extend_from_slice(id.as_bytes());
+        }
+
+        let mut reader = &bytes[..];
+        assert_eq!(OcidV0::read_length_prefixed(&mut reader).unwrap(), a);
+        assert_eq!(OcidV0::read_length_prefixed(&mut reader).unwrap(), b);
+
+        let error = OcidV0::read_length_prefixed(&mut reader).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        // Truncated ID.
+        let mut reader = &bytes[..LEN];
+        let error = OcidV0::read_length_prefixed(&mut reader).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        // Wrong prefix.
+        bytes[0] = 40;
+        let error = OcidV0::read_length_prefixed(&mut &bytes[..]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let inner = error.get_ref().unwrap().downcast_ref::<ParseError>();
+        assert_eq!(inner, Some(&ParseError::InvalidLength(40)));
+
+        // Wrong version.
+        bytes[0] = LEN as u8;
+        bytes[1] = 1;
+        let error = OcidV0::read_length_prefixed(&mut &bytes[..]).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn encode_base64_to_writer() {
+        let id = OcidV0::rand(rand_core::OsRng);
+
+        let mut buf = [0u8; BASE64_LEN];
+        let expected = id.encode_base64(&mut buf);
+
+        let mut writer = b"id: ".to_vec();
+        id.encode_base64_to_writer(&mut writer).unwrap();
+        assert_eq!(&writer[..4], b"id: ");
+        assert_eq!(&writer[4..], expected.as_bytes());
+    }
+
+    #[test]
+    fn from_reader() {
+        let mut content = vec![0u8; BUF_LEN * 3 + 17];
+        rand_core::OsRng.fill_bytes(&mut content);
+
+        let id = OcidV0::new(&content);
+        assert_eq!(OcidV0::from_reader(&content[..]).unwrap(), id);
+        assert_eq!(OcidV0::from_reader(io::empty()).unwrap(), OcidV0::new(&[]));
+    }
+
+    #[test]
+    fn verify_reader_detailed() {
+        let content = b"hello, world";
+        let id = OcidV0::new(content).unwrap();
+        let expected = content.len() as u64;
+
+        let outcome = id.verify_reader_detailed(&content[..]).unwrap();
+        assert_eq!(outcome, VerifyOutcome::Match);
+
+        let outcome = id.verify_reader_detailed(&content[..5]).unwrap();
+        let actual = 5;
+        assert_eq!(outcome, VerifyOutcome::SizeMismatch { expected, actual });
+
+        // Reading stops one byte past the expected size.
+        let outcome = id.verify_reader_detailed(&[0u8; 100][..]).unwrap();
+        let actual = expected + 1;
+        assert_eq!(outcome, VerifyOutcome::SizeMismatch { expected, actual });
+
+        let outcome = id.verify_reader_detailed(&b"hello, World"[..]).unwrap();
+        assert_eq!(outcome, VerifyOutcome::HashMismatch);
+    }
+
+    #[test]
+    fn from_reader_with_progress() {
+        let mut content = vec![0u8; BUF_LEN * 3 + 17];
+        rand_core::OsRng.fill_bytes(&mut content);
+
+        let mut calls = Vec::new();
+        let id = OcidV0::from_reader_with_progress(&content[..], |bytes| {
+            calls.push(bytes);
+        });
+
+        assert_eq!(id.unwrap(), OcidV0::new(&content));
+        assert_eq!(
+            calls,
+            [BUF_LEN, BUF_LEN * 2, BUF_LEN * 3, content.len()]
+                .iter()
+                .map(|&len| len as u64)
+                .collect::<Vec<_>>(),
+        );
+
+        let mut called = false;
+        let id = OcidV0::from_reader_with_progress(io::empty(), |_| {
+            called = true;
+        });
+        assert_eq!(id.unwrap(), OcidV0::new(&[]));
+        assert!(!called);
+    }
+
+    #[test]
+    fn from_dir() {
+        // Include the process ID to not clash with concurrent test runs.
+        let name = format!("ocid-v0-from-dir-{}", std::process::id());
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("sub/empty")).unwrap();
+        std::fs::write(dir.join("b.txt"), b"world").unwrap();
+        std::fs::write(dir.join("a.txt"), b"hello").unwrap();
+        std::fs::write(dir.join("sub/c.txt"), b"!").unwrap();
+
+        let id = OcidV0::from_dir(&dir);
+
+        // Renaming a file changes the ID.
+        std::fs::rename(dir.join("b.txt"), dir.join("d.txt")).unwrap();
+        let renamed = OcidV0::from_dir(&dir);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let mut hasher = blake3::Hasher::new();
+        for (path, content) in &[
+            ("a.txt", &b"hello"[..]),
+            ("b.txt", b"world"),
+            ("sub/c.txt", b"!"),
+        ] {
+            hasher.update(&(path.len() as u64).to_be_bytes());
+            hasher.update(path.as_bytes());
+            hasher.update(&(content.len() as u64).to_be_bytes());
+            hasher.update(content);
+        }
+
+        let id = id.unwrap().unwrap();
+        assert_eq!(id.size(), 11);
+        assert_eq!(id.hash(), hasher.finalize().as_bytes());
+
+        let renamed = renamed.unwrap().unwrap();
+        assert_eq!(renamed.size(), 11);
+        assert_ne!(renamed, id);
+    }
+
+    #[test]
+    fn from_path() {
+        let content = b"hello, world";
+
+        // Include the process ID to not clash with concurrent test runs.
+        let name = format!("ocid-v0-from-path-{}", std::process::id());
+        let path = std::env::temp_dir().join(name);
+        std::fs::write(&path, content).unwrap();
+        let id = OcidV0::from_path(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(id.unwrap(), OcidV0::new(content));
+    }
+}
@@ -0,0 +1,118 @@
+use core::{
+    iter::{Enumerate, FusedIterator},
+    str::Lines,
+};
+
+use super::{LineError, OcidV0, ParseError, LEN};
+
+/// An iterator over the IDs in a byte slice.
+///
+/// This `struct` is created by
+/// [`OcidV0::iter_from_slice`](struct.OcidV0.html#method.iter_from_slice).
+#[derive(Clone, Debug)]
+pub struct SliceIter<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> SliceIter<'a> {
+    #[inline]
+    pub(super) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the bytes that have not yet been iterated over.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> Iterator for SliceIter<'a> {
+    type Item = Result<&'a OcidV0, ParseError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        match OcidV0::from_slice(self.bytes) {
+            Some((id, tail)) => {
+                self.bytes = tail;
+                Some(Ok(id))
+            }
+            None if self.bytes.len() < LEN => {
+                let len = self.bytes.len();
+                self.bytes = &[];
+                Some(Err(ParseError::Truncated(len)))
+            }
+            None => {
+                let version = self.bytes[0];
+                self.bytes = &self.bytes[LEN..];
+                Some(Err(ParseError::UnexpectedVersion(version)))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for SliceIter<'_> {
+    #[inline]
+    fn len(&self) -> usize {
+        // A trailing partial ID is yielded as an error.
+        self.bytes.chunks(LEN).len()
+    }
+}
+
+impl FusedIterator for SliceIter<'_> {}
+
+/// An iterator over the IDs listed one per line in text.
+///
+/// This `struct` is created by
+/// [`OcidV0::parse_lines`](struct.OcidV0.html#method.parse_lines).
+#[derive(Clone, Debug)]
+pub struct LinesIter<'a> {
+    lines: Enumerate<Lines<'a>>,
+}
+
+impl<'a> LinesIter<'a> {
+    #[inline]
+    pub(super) fn new(text: &'a str) -> Self {
+        Self {
+            lines: text.lines().enumerate(),
+        }
+    }
+}
+
+impl Iterator for LinesIter<'_> {
+    type Item = Result<OcidV0, LineError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, line) in &mut self.lines {
+            let line = line.trim_matches(|c: char| c.is_ascii_whitespace());
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            return Some(line.parse().map_err(|error| LineError {
+                line: index + 1,
+                error,
+            }));
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Any line may be skipped.
+        (0, self.lines.size_hint().1)
+    }
+}
+
+impl FusedIterator for LinesIter<'_> {}
@@ -5,14 +5,77 @@ use core::{
     convert::TryFrom,
     fmt, hash,
     mem::{self, MaybeUninit},
-    slice,
+    slice, str,
 };
 
+mod error;
+mod iter;
+mod non_empty;
 mod raw;
+
+use crate::{
+    enc::{base64, hex},
+    Ocid, OcidError,
+};
+
+#[cfg(feature = "digest")]
+mod digest;
+
+#[cfg(any(test, docsrs, feature = "blake3"))]
+mod hasher;
+
+#[cfg(feature = "heapless")]
+mod heapless;
+
+#[cfg(feature = "multihash")]
+mod multihash;
+
+#[cfg(feature = "std")]
+mod io;
+
+#[cfg(feature = "nightly-simd")]
+mod simd;
+
+#[cfg(feature = "prost")]
+mod prost;
+
+#[cfg(feature = "rayon")]
+mod par;
+
+#[cfg(feature = "postcard")]
+mod postcard;
+
+#[cfg(feature = "redis")]
+mod redis;
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "tokio")]
+mod tokio;
+
+#[cfg(feature = "digest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+pub use self::digest::OcidV0Digest;
+pub use error::{LineError, ParseError};
+#[cfg(any(test, docsrs, feature = "blake3"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "blake3")))]
+pub use hasher::{OcidV0Hasher, SizeExceeded};
+#[cfg(all(feature = "std", any(test, docsrs, feature = "blake3")))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "blake3"))))]
+pub use io::VerifyOutcome;
+pub use iter::{LinesIter, SliceIter};
+pub use non_empty::NonEmptyOcidV0;
 pub use raw::RawOcidV0;
 
-const LEN: usize = 39;
-const BASE64_LEN: usize = 52;
+pub(crate) const LEN: usize = 39;
+pub(crate) const BASE64_LEN: usize = 52;
 
 #[inline]
 fn size_bytes_from_u64(size: u64) -> Option<[u8; 6]> {
@@ -71,6 +134,7 @@ fn size_bytes_from_u64(size: u64) -> Option<[u8; 6]> {
 /// [BLAKE3]:                https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
 /// [lexicographical order]: https://en.wikipedia.org/wiki/Lexicographical_order
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct OcidV0(RawOcidV0);
 
 impl PartialEq for OcidV0 {
@@ -96,10 +160,22 @@ impl Ord for OcidV0 {
     fn cmp(&self, other: &Self) -> cmp::Ordering {
         // Compare bodies since comparing fields seems to generate a call to
         // `memcmp` anyway.
-        self.body().cmp(other.body())
+        #[cfg(not(feature = "nightly-simd"))]
+        {
+            self.body().cmp(other.body())
+        }
+
+        #[cfg(feature = "nightly-simd")]
+        {
+            simd::cmp_body(self.body(), other.body())
+        }
     }
 }
 
+/// Writes all 39 bytes of the ID to the hasher in a single call.
+///
+/// For maps that should skip rehashing the already-random content hash, see
+/// [`identity_u64`](#method.identity_u64).
 impl hash::Hash for OcidV0 {
     #[inline]
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
@@ -130,10 +206,91 @@ impl fmt::Display for OcidV0 {
     }
 }
 
+impl From<&OcidV0> for [u8; LEN] {
+    #[inline]
+    fn from(id: &OcidV0) -> Self {
+        id.into_bytes()
+    }
+}
+
+impl<'a> From<&'a OcidV0> for &'a [u8] {
+    #[inline]
+    fn from(id: &'a OcidV0) -> Self {
+        id.as_bytes()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl TryFrom<alloc::vec::Vec<u8>> for OcidV0 {
+    type Error = alloc::vec::Vec<u8>;
+
+    /// Creates an ID from exactly 39 raw bytes, returning `bytes` back if it
+    /// has the wrong length or the version is not 0.
+    #[inline]
+    fn try_from(bytes: alloc::vec::Vec<u8>) -> Result<Self, Self::Error> {
+        match <[u8; LEN]>::try_from(&bytes[..]) {
+            Ok(raw) => Self::from_bytes(raw).ok_or(bytes),
+            Err(_) => Err(bytes),
+        }
+    }
+}
+
+impl str::FromStr for OcidV0 {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut id = Self::empty();
+        Self::decode_base64_into(s, &mut id)?;
+        Ok(id)
+    }
+}
+
 impl OcidV0 {
+    /// The maximum content size that can be represented by an ID:
+    /// 2<sup>48</sup> - 1.
+    pub const MAX_SIZE: u64 = (1 << 48) - 1;
+
+    /// The ID of empty content: a size of 0 and the [BLAKE3] hash of no bytes.
+    ///
+    /// This is equal to `OcidV0::new(b"")` and is distinct from
+    /// [`empty`](#method.empty), which is an all-zero sentinel that is not the
+    /// ID of any content.
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[rustfmt::skip]
+    pub const EMPTY_CONTENT: OcidV0 = Self::from_parts([0; 6], [
+        0xAF, 0x13, 0x49, 0xB9, 0xF5, 0xF9, 0xA1, 0xA6,
+        0xA0, 0x40, 0x4D, 0xEA, 0x36, 0xDC, 0xC9, 0x49,
+        0x9B, 0xCB, 0x25, 0xC9, 0xAD, 0xC1, 0x12, 0xB7,
+        0xCC, 0x9A, 0x93, 0xCA, 0xE4, 0x1F, 0x32, 0x62,
+    ]);
+
+    /// The number of bytes in the wire format of an ID.
+    ///
+    /// See [`to_wire`](#method.to_wire) for details.
+    pub const WIRE_LEN: usize = LEN;
+
+    /// The number of characters in the [Base64] encoding of an ID.
+    ///
+    /// This is the length of the buffer taken by
+    /// [`encode_base64`](#method.encode_base64).
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    pub const BASE64_LEN: usize = BASE64_LEN;
+
+    /// Returns whether `size` can be represented by an ID, i.e. if it is not
+    /// larger than [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    #[inline]
+    pub const fn size_fits(size: u64) -> bool {
+        size <= Self::MAX_SIZE
+    }
+
     /// Generates an ID by hashing `content` using [BLAKE3].
     ///
-    /// Returns `None` if `content` is larger than 2<sup>48</sup> - 1.
+    /// Returns `None` if `content` is larger than
+    /// [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
     ///
     /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
     #[cfg(any(test, docsrs, feature = "blake3"))]
@@ -148,10 +305,136 @@ impl OcidV0 {
         Some(Self::from_parts(size, hash.into()))
     }
 
+    /// Generates an ID by hashing `content` using [BLAKE3] with `hasher`,
+    /// such as for hashing many buffers in a loop with one hasher.
+    ///
+    /// `hasher` is reset before hashing `content`, so any previous state is
+    /// discarded. Returns `None` if `content` is larger than
+    /// [`MAX_SIZE`](#associatedconstant.MAX_SIZE), in which case `hasher` is
+    /// left unchanged.
+    ///
+    /// # Performance
+    ///
+    /// Reusing a hasher gives no measurable gain over [`new`](#method.new)
+    /// (see `benches/new_with.rs`). This exists for callers that already hold
+    /// a `blake3::Hasher`.
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[cfg(any(test, docsrs, feature = "blake3"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "blake3")))]
+    #[inline]
+    pub fn new_with(
+        hasher: &mut blake3::Hasher,
+        content: &[u8],
+    ) -> Option<OcidV0> {
+        let size = u64::try_from(content.len()).ok()?;
+        let size = size_bytes_from_u64(size)?;
+
+        let hash = hasher.reset().update(content).finalize();
+
+        Some(Self::from_parts(size, hash.into()))
+    }
+
+    /// Generates an ID by hashing `content` using [BLAKE3], where `content`
+    /// is any type that can be viewed as bytes, such as `Vec<u8>` or `String`.
+    ///
+    /// This is the same as [`new`](#method.new) but without requiring a
+    /// conversion to `&[u8]` at the call site.
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[cfg(any(test, docsrs, feature = "blake3"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "blake3")))]
+    #[inline]
+    pub fn new_from<T: AsRef<[u8]>>(content: T) -> Option<OcidV0> {
+        Self::new(content.as_ref())
+    }
+
+    /// Creates an ID from an existing [BLAKE3] `hash` of content with `size`.
+    ///
+    /// This avoids rehashing content that was already hashed elsewhere.
+    /// Returns `None` if `size` is larger than
+    /// [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[cfg(any(test, docsrs, feature = "blake3"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "blake3")))]
+    #[inline]
+    pub fn from_blake3(hash: blake3::Hash, size: u64) -> Option<OcidV0> {
+        Self::from_parts_u64(size, hash.into())
+    }
+
+    /// Generates an ID by hashing the concatenation of `chunks` using
+    /// [BLAKE3].
+    ///
+    /// This produces the same ID as calling [`new`](#method.new) on all chunks
+    /// concatenated, without needing to allocate a buffer for them. Returns
+    /// `None` if the total size is larger than
+    /// [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[cfg(any(test, docsrs, feature = "blake3"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "blake3")))]
+    #[inline]
+    pub fn new_chunks<I, B>(chunks: I) -> Option<OcidV0>
+    where
+        I: IntoIterator<Item = B>,
+        B: AsRef<[u8]>,
+    {
+        let mut hasher = OcidV0Hasher::new();
+        for chunk in chunks {
+            hasher.update_unbounded(chunk.as_ref());
+        }
+        hasher.finalize()
+    }
+
+    /// Generates an ID by hashing `parts` one after another using [BLAKE3].
+    ///
+    /// The result is the same as calling [`new`](#method.new) on all parts
+    /// concatenated, which suits manifest-style hashing where several fields
+    /// make up one piece of content. Returns `None` if the total size is larger
+    /// than [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    ///
+    /// For content that is not all available at once, use
+    /// [`OcidV0Hasher`](v0/struct.OcidV0Hasher.html).
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[cfg(any(test, docsrs, feature = "blake3"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "blake3")))]
+    #[inline]
+    pub fn concat_hash(parts: &[&[u8]]) -> Option<OcidV0> {
+        Self::new_chunks(parts)
+    }
+
+    /// Generates the ID of a manifest made up of `children`, such as for a
+    /// two-level content tree.
+    ///
+    /// The hash is the [BLAKE3] hash of the 39-byte forms of `children`
+    /// concatenated in order, and the size is the sum of their sizes. Returns
+    /// `None` if that sum is larger than
+    /// [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    ///
+    /// This identifies the manifest itself, not the concatenated content of the
+    /// children, which has a different hash. Its size is also not the size of
+    /// the hashed bytes, so [`size_matches`](#method.size_matches) does not
+    /// hold for the manifest bytes.
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[cfg(any(test, docsrs, feature = "blake3"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "blake3")))]
+    pub fn parent_of(children: &[OcidV0]) -> Option<OcidV0> {
+        let size = children
+            .iter()
+            .try_fold(0u64, |size, child| size.checked_add(child.size()))?;
+
+        let hash = blake3::hash(Self::slice_as_bytes(children));
+        Self::from_parts_u64(size, hash.into())
+    }
+
     /// Generates a random ID from `rng`.
     ///
     /// If the generated ID has a size of zero, this will attempt once to
-    /// generate a non-zero size.
+    /// generate a non-zero size. Use [`rand_raw`](#method.rand_raw) for
+    /// unbiased draws.
     #[cfg(any(test, docsrs, feature = "rand_core"))]
     #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
     #[inline]
@@ -171,6 +454,23 @@ impl OcidV0 {
         id
     }
 
+    /// Generates a random ID from `rng` without preferring a non-zero size.
+    ///
+    /// Unlike [`rand`](#method.rand), the body is filled exactly once, and so
+    /// the resulting ID may have a size of zero. This makes every ID equally
+    /// likely, which is useful for simulations and tests.
+    #[cfg(any(test, docsrs, feature = "rand_core"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+    #[inline]
+    pub fn rand_raw<R>(mut rng: R) -> OcidV0
+    where
+        R: rand_core::RngCore,
+    {
+        let mut id = Self::empty();
+        rng.fill_bytes(id.body_mut());
+        id
+    }
+
     /// Attempts to generate a random ID from `rng`, returning an error upon
     /// failure.
     ///
@@ -195,6 +495,36 @@ impl OcidV0 {
         Ok(id)
     }
 
+    /// Deterministically derives an arbitrary non-empty ID from `seed`, such
+    /// as for test fixtures.
+    ///
+    /// The ID is the same for a given `seed` across runs and platforms. It is
+    /// not the ID of any known content and must not be used for content
+    /// addressing. Use [`rand`](#method.rand) for random IDs.
+    pub fn from_seed(seed: u64) -> OcidV0 {
+        // SplitMix64, since it is small and well-specified.
+        let mut state = seed;
+        let mut next = || {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+
+        let size = next() % Self::MAX_SIZE + 1;
+
+        let mut hash = [0u8; 32];
+        for chunk in hash.chunks_exact_mut(8) {
+            chunk.copy_from_slice(&next().to_le_bytes());
+        }
+
+        match Self::from_parts_u64(size, hash) {
+            Some(id) => id,
+            None => unreachable!("seeded size is larger than `MAX_SIZE`"),
+        }
+    }
+
     /// Creates an ID from `size` and `hash`.
     #[inline]
     pub const fn from_parts(size: [u8; 6], hash: [u8; 32]) -> OcidV0 {
@@ -205,6 +535,259 @@ impl OcidV0 {
         })
     }
 
+    /// Creates an ID from a native integer `size` and `hash`.
+    ///
+    /// Returns `None` if `size` is larger than
+    /// [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    #[inline]
+    pub fn from_parts_u64(size: u64, hash: [u8; 32]) -> Option<OcidV0> {
+        let size = size_bytes_from_u64(size)?;
+        Some(Self::from_parts(size, hash))
+    }
+
+    /// Creates an ID from a legacy record that stored only the 32-byte
+    /// [BLAKE3] `hash` of content.
+    ///
+    /// # Migration
+    ///
+    /// Legacy records did not store the content size, so it must be recovered
+    /// separately, such as from file metadata. Because the hash is unchanged,
+    /// the resulting ID is the same as calling [`new`](#method.new) on the
+    /// original content, as long as `size` is correct. Prefer rehashing the
+    /// content when it is available, since a wrong `size` cannot be detected
+    /// here.
+    ///
+    /// Returns `None` if `size` is larger than
+    /// [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[inline]
+    pub fn from_legacy_hash(hash: [u8; 32], size: u64) -> Option<OcidV0> {
+        Self::from_parts_u64(size, hash)
+    }
+
+    /// Decodes the [Base64] encoding of an ID in `s` into `out`.
+    ///
+    /// `out` is left unchanged if decoding fails, including when the decoded
+    /// version is not 0.
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    #[inline]
+    pub fn decode_base64_into(
+        s: &str,
+        out: &mut OcidV0,
+    ) -> Result<(), base64::DecodeError> {
+        let bytes = Self::decode_base64_raw(s.as_bytes())?;
+        out.body_mut().copy_from_slice(&bytes[1..]);
+        Ok(())
+    }
+
+    /// Decodes an ID from the [Base64] encoding in `bytes`.
+    ///
+    /// Unlike parsing a `&str`, this does not require `bytes` to be validated
+    /// as UTF-8 beforehand. Each byte is checked against the Base64 alphabet,
+    /// which only contains ASCII characters, so any non-ASCII byte results in
+    /// [`DecodeError::InvalidChar`].
+    ///
+    /// [`DecodeError::InvalidChar`]: enc/base64/enum.DecodeError.html#variant.InvalidChar
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    #[inline]
+    pub fn decode_base64_bytes(
+        bytes: &[u8],
+    ) -> Result<OcidV0, base64::DecodeError> {
+        let bytes = Self::decode_base64_raw(bytes)?;
+
+        // SAFETY: `decode_base64_raw` only succeeds for version 0.
+        Ok(unsafe { Self::from_bytes_unchecked(bytes) })
+    }
+
+    /// Decodes the [Base64] `input` into the bytes of a version 0 ID.
+    ///
+    /// The version is only reported if `input` is otherwise well-formed, so
+    /// that arbitrary text is not mistaken for an ID of another version.
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    fn decode_base64_raw(
+        input: &[u8],
+    ) -> Result<[u8; LEN], base64::DecodeError> {
+        use base64::DecodeError;
+
+        if input.len() == BASE64_LEN {
+            let bytes = base64::decode_base8_39(input)?;
+            return match bytes[0] {
+                0 => Ok(bytes),
+                version => Err(DecodeError::UnexpectedVersion(version)),
+            };
+        }
+
+        // IDs of other versions may be longer, but are still made of whole
+        // 4-character groups.
+        let groups = input.len() / 4;
+        if input.len() < BASE64_LEN || groups * 4 != input.len() {
+            return Err(DecodeError::InvalidLength(input.len()));
+        }
+
+        if let Some(index) =
+            input.iter().position(|&b| !base64::is_valid_char(b))
+        {
+            let byte = input[index];
+            return Err(DecodeError::InvalidChar { index, byte });
+        }
+
+        match base64::decode_first_byte(input) {
+            Some(version) if version != 0 => {
+                Err(DecodeError::UnexpectedVersion(version))
+            }
+            _ => Err(DecodeError::InvalidLength(input.len())),
+        }
+    }
+
+    /// Returns whether `s` looks like the [Base64] encoding of an ID, such as
+    /// for telling IDs apart from package names in user input.
+    ///
+    /// This is a cheap heuristic that only checks that `s` is 52 characters in
+    /// the Base64 alphabet. It does not decode `s`, and so does not check that
+    /// the version is 0. Use [`FromStr`] for full validation.
+    ///
+    /// [Base64]:    https://en.wikipedia.org/wiki/Base64
+    /// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+    #[inline]
+    pub fn looks_like(s: &str) -> bool {
+        s.len() == BASE64_LEN && s.bytes().all(base64::is_valid_char)
+    }
+
+    /// Parses an ID from its [Base64] encoding, leniently.
+    ///
+    /// Unlike the exact [`FromStr`] implementation, this ignores leading and
+    /// trailing ASCII whitespace as well as an `ocid:0:` or `ocid:` prefix.
+    ///
+    /// [Base64]:    https://en.wikipedia.org/wiki/Base64
+    /// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+    #[inline]
+    pub fn parse_lenient(s: &str) -> Result<OcidV0, ParseError> {
+        let s = s.trim_matches(|c: char| c.is_ascii_whitespace());
+
+        let s = if let Some(s) = s.strip_prefix("ocid:0:") {
+            s
+        } else if let Some(s) = s.strip_prefix("ocid:") {
+            s
+        } else {
+            s
+        };
+
+        s.parse()
+    }
+
+    /// Returns an iterator over the IDs listed one per line in `text`, such as
+    /// in a lockfile.
+    ///
+    /// Each line is trimmed of leading and trailing ASCII whitespace. Blank
+    /// lines and lines starting with `#` are skipped. Every other line is
+    /// parsed as an ID, with errors reporting the line number.
+    ///
+    /// Lines are parsed lazily as the iterator advances.
+    #[inline]
+    pub fn parse_lines(text: &str) -> LinesIter<'_> {
+        LinesIter::new(text)
+    }
+
+    /// Parses an ID from the [hexadecimal] encoding of all 39 of its bytes,
+    /// such as from [`to_hex_string`](#method.to_hex_string).
+    ///
+    /// Both lowercase and uppercase digits are accepted. Returns an error if
+    /// `s` is not 78 hexadecimal digits or if the decoded version is not 0.
+    ///
+    /// [hexadecimal]: https://en.wikipedia.org/wiki/Hexadecimal
+    #[inline]
+    pub fn from_hex_str(s: &str) -> Result<OcidV0, ParseError> {
+        let mut wire = [0u8; LEN];
+        hex::decode(s.as_bytes(), &mut wire).map_err(ParseError::Hex)?;
+        Self::from_wire(&wire)
+    }
+
+    /// Creates an ID from its wire format.
+    ///
+    /// This is the inverse of [`to_wire`](#method.to_wire). Returns an error if
+    /// the version is not 0.
+    #[inline]
+    pub fn from_wire(wire: &[u8; LEN]) -> Result<OcidV0, ParseError> {
+        Self::from_bytes(*wire).ok_or(ParseError::UnexpectedVersion(wire[0]))
+    }
+
+    /// Creates an ID from its wire format in a slice of unknown length, such
+    /// as a database or protocol field.
+    ///
+    /// Returns [`ParseError::InvalidLength`] if `wire` is not 39 bytes, or
+    /// [`ParseError::UnexpectedVersion`] if the version is not 0.
+    ///
+    /// [`ParseError::InvalidLength`]:     v0/enum.ParseError.html#variant.InvalidLength
+    /// [`ParseError::UnexpectedVersion`]: v0/enum.ParseError.html#variant.UnexpectedVersion
+    #[inline]
+    pub fn from_wire_slice(wire: &[u8]) -> Result<OcidV0, ParseError> {
+        match <&[u8; LEN]>::try_from(wire) {
+            Ok(wire) => Self::from_wire(wire),
+            Err(_) => Err(ParseError::InvalidLength(wire.len())),
+        }
+    }
+
+    /// Creates an ID from its bytes packed into 32-bit words.
+    ///
+    /// This is the inverse of [`as_u32_words`](#method.as_u32_words). The
+    /// lowest byte of the last word is padding and is ignored. Returns `None`
+    /// if the version is not 0.
+    #[inline]
+    pub fn from_u32_words(words: [u32; 10]) -> Option<OcidV0> {
+        let mut bytes = [0u8; 40];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(words.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+
+        let mut id = [0u8; LEN];
+        id.copy_from_slice(&bytes[..LEN]);
+        Self::from_bytes(id)
+    }
+
+    /// Creates an ID from its bytes packed into integers.
+    ///
+    /// This is the inverse of [`to_u64_array`](#method.to_u64_array). The
+    /// lowest byte of the last integer is padding and is ignored. Returns
+    /// `None` if the version is not 0.
+    #[inline]
+    pub fn from_u64_array(array: [u64; 5]) -> Option<OcidV0> {
+        let mut bytes = [0u8; 40];
+        for (chunk, int) in bytes.chunks_exact_mut(8).zip(array.iter()) {
+            chunk.copy_from_slice(&int.to_be_bytes());
+        }
+
+        let mut id = [0u8; LEN];
+        id.copy_from_slice(&bytes[..LEN]);
+        Self::from_bytes(id)
+    }
+
+    /// Parses an ID from its [Base64] encoding followed by a checksum
+    /// character, as produced by [`to_string_checked`].
+    ///
+    /// [Base64]:              https://en.wikipedia.org/wiki/Base64
+    /// [`to_string_checked`]: #method.to_string_checked
+    pub fn parse_checked(s: &str) -> Result<OcidV0, ParseError> {
+        let (found, b64) = match s.as_bytes().split_last() {
+            Some((&found, b64)) => (found, b64),
+            None => return Err(base64::DecodeError::InvalidLength(0).into()),
+        };
+
+        let id = Self::decode_base64_bytes(b64)?;
+
+        // Decoding succeeded, so `b64` is the exact encoding of `id`.
+        let expected = base64::checksum_39(b64);
+
+        if found == expected {
+            Ok(id)
+        } else {
+            Err(ParseError::ChecksumMismatch { expected, found })
+        }
+    }
+
     /// Creates an ID from the raw internals.
     #[inline]
     pub fn from_raw(raw: RawOcidV0) -> Option<OcidV0> {
@@ -311,38 +894,351 @@ impl OcidV0 {
         }
     }
 
-    /// Creates an ID that represents an empty file.
+    /// Returns the number of whole IDs that fit in `bytes`.
     #[inline]
-    pub const fn empty() -> OcidV0 {
-        Self::from_parts([0; 6], [0; 32])
+    pub const fn count_in(bytes: &[u8]) -> usize {
+        bytes.len() / LEN
     }
 
-    /// Returns a slice of raw IDs for all of `ids`.
+    /// Returns whether `bytes` can be split into IDs without any left over,
+    /// i.e. if its length is a multiple of 39.
     #[inline]
-    pub fn slice_as_raw(ids: &[Self]) -> &[RawOcidV0] {
-        let ptr = ids.as_ptr() as *const RawOcidV0;
-        unsafe { slice::from_raw_parts(ptr, ids.len()) }
+    pub fn fits_exactly(bytes: &[u8]) -> bool {
+        bytes.chunks_exact(LEN).remainder().is_empty()
     }
 
-    /// Returns a slice of bytes for all of `ids`.
+    /// Checks that `bytes` consists entirely of valid IDs, without
+    /// constructing any of them.
+    ///
+    /// Returns the number of IDs on success. On failure, returns the byte
+    /// offset of the first ID with a non-zero version, or of the trailing
+    /// partial ID if the length of `bytes` is not a multiple of 39.
     #[inline]
-    pub fn slice_as_bytes(ids: &[Self]) -> &[u8] {
-        let ptr = ids.as_ptr() as *const u8;
-        let len = ids.len() * LEN;
-        unsafe { slice::from_raw_parts(ptr, len) }
+    pub fn validate_slice(bytes: &[u8]) -> Result<usize, usize> {
+        let chunks = bytes.chunks_exact(LEN);
+        let partial = bytes.len() - chunks.remainder().len();
+
+        match chunks.clone().position(|id| id[0] != 0) {
+            Some(index) => Err(index * LEN),
+            None if partial != bytes.len() => Err(partial),
+            None => Ok(chunks.len()),
+        }
     }
 
-    /// Returns the ID version.
+    /// Returns an iterator over the IDs in `bytes`.
     ///
-    /// In correct code, this always returns 0.
+    /// Unlike [`from_bytes_slice`], this does not reject all of `bytes` if
+    /// part of it is invalid. Instead, an error is yielded for each ID with a
+    /// non-zero version, as well as for a trailing partial ID.
+    ///
+    /// [`from_bytes_slice`]: #method.from_bytes_slice
     #[inline]
-    pub fn version(&self) -> u8 {
-        let version = self.0.version;
-        debug_assert_eq!(version, 0, "{} is not version 0", self);
-        version
+    pub fn iter_from_slice(bytes: &[u8]) -> SliceIter<'_> {
+        SliceIter::new(bytes)
     }
 
-    /// Returns the size of the source content as a native integer.
+    /// Creates a slice of IDs from the raw bytes of all of them.
+    ///
+    /// This is the inverse of [`slice_as_bytes`]. Returns `None` if the length
+    /// of `bytes` is not a multiple of 39 or if any ID has a non-zero version.
+    ///
+    /// [`slice_as_bytes`]: #method.slice_as_bytes
+    #[inline]
+    pub fn from_bytes_slice(bytes: &[u8]) -> Option<&[OcidV0]> {
+        let len = Self::validate_slice(bytes).ok()?;
+
+        let ptr = bytes.as_ptr() as *const Self;
+        Some(unsafe { slice::from_raw_parts(ptr, len) })
+    }
+
+    /// Returns the IDs in `data` that are within `lo..=hi`, where `data` is
+    /// a sorted array of IDs in their raw form, such as a memory-mapped index
+    /// file.
+    ///
+    /// This binary-searches both bounds in O(log n) time without copying any
+    /// IDs. Only the length of `data` is checked up front, so that pages of a
+    /// memory map are not read unless the search reaches them. The versions of
+    /// IDs are checked as the search probes them, as well as for every ID in
+    /// the result.
+    ///
+    /// Returns `None` if the length of `data` is not a multiple of 39 or if a
+    /// checked ID has a non-zero version. Invalid IDs that are never checked
+    /// go unnoticed. Returns an empty slice if `lo > hi`. If `data` is not
+    /// sorted, the result is unspecified but still a subslice of `data`.
+    #[inline]
+    pub fn range_in_mmap<'a>(
+        data: &'a [u8],
+        lo: &OcidV0,
+        hi: &OcidV0,
+    ) -> Option<&'a [OcidV0]> {
+        let raw = Self::raw_slice(data)?;
+        if lo > hi {
+            return Some(&[]);
+        }
+
+        let start = Self::partition_point_checked(raw, |id| id < lo)?;
+        let raw = &raw[start..];
+        let end = Self::partition_point_checked(raw, |id| id <= hi)?;
+
+        Self::from_bytes_slice(RawOcidV0::slice_as_bytes(&raw[..end]))
+    }
+
+    /// Returns whether `target` is in `data`, where `data` is a sorted array
+    /// of IDs in their raw form, such as a memory-mapped index file.
+    ///
+    /// Like [`range_in_mmap`], this binary-searches `data` in O(log n) time
+    /// and only checks the versions of the IDs that the search probes.
+    /// Returns `None` if the length of `data` is not a multiple of 39 or if a
+    /// probed ID has a non-zero version. If `data` is not sorted, the result is
+    /// unspecified.
+    ///
+    /// [`range_in_mmap`]: #method.range_in_mmap
+    #[inline]
+    pub fn is_in_sorted_mmap(data: &[u8], target: &OcidV0) -> Option<bool> {
+        let raw = Self::raw_slice(data)?;
+        let index = Self::partition_point_checked(raw, |id| id < target)?;
+
+        match raw.get(index) {
+            Some(raw) => Some(Self::from_raw(*raw)? == *target),
+            None => Some(false),
+        }
+    }
+
+    /// Views `data` as raw IDs without reading any of them, for searching
+    /// sorted data in [`range_in_mmap`](#method.range_in_mmap) and
+    /// [`is_in_sorted_mmap`](#method.is_in_sorted_mmap).
+    #[inline]
+    fn raw_slice(data: &[u8]) -> Option<&[RawOcidV0]> {
+        if !Self::fits_exactly(data) {
+            return None;
+        }
+
+        // SAFETY: `RawOcidV0` is 39 bytes with an alignment of 1, and any
+        // bytes are a valid `RawOcidV0`.
+        let ptr = data.as_ptr() as *const RawOcidV0;
+        Some(unsafe { slice::from_raw_parts(ptr, data.len() / LEN) })
+    }
+
+    /// Returns the index of the first ID in `raw` for which `pred` is false,
+    /// or `None` if the binary search probes an ID with a non-zero version.
+    #[inline]
+    fn partition_point_checked<F>(
+        raw: &[RawOcidV0],
+        mut pred: F,
+    ) -> Option<usize>
+    where
+        F: FnMut(&OcidV0) -> bool,
+    {
+        let mut valid = true;
+        let index = raw.partition_point(|raw| {
+            match Self::from_bytes_ref(raw.as_bytes()) {
+                Some(id) => pred(id),
+                None => {
+                    valid = false;
+                    false
+                }
+            }
+        });
+
+        if valid {
+            Some(index)
+        } else {
+            None
+        }
+    }
+
+    /// Overwrites the size of the ID at `index` in `bytes`, where `bytes` is an
+    /// array of IDs in their raw form, such as a memory-mapped index file.
+    ///
+    /// This allows repairing a corrupted size without rewriting the rest of
+    /// the data. The ID occupies `bytes[index * 39..][..39]`, and only its 6
+    /// size bytes are written.
+    ///
+    /// # Errors
+    ///
+    /// Nothing is written if:
+    ///
+    /// - The length of `bytes` is not a multiple of 39, returning
+    ///   [`TrailingBytes`]. This guards against writing to the wrong bytes of
+    ///   a truncated or misaligned file.
+    /// - `index` is not less than [`count_in(bytes)`], returning
+    ///   [`IndexOutOfBounds`].
+    /// - The version of the ID at `index` is not 0, returning
+    ///   [`UnknownVersion`].
+    /// - `new_size` is larger than [`MAX_SIZE`], returning [`SizeTooLarge`].
+    ///
+    /// [`count_in(bytes)`]:  #method.count_in
+    /// [`MAX_SIZE`]:         #associatedconstant.MAX_SIZE
+    /// [`TrailingBytes`]:    enum.OcidError.html#variant.TrailingBytes
+    /// [`IndexOutOfBounds`]: enum.OcidError.html#variant.IndexOutOfBounds
+    /// [`UnknownVersion`]:   enum.OcidError.html#variant.UnknownVersion
+    /// [`SizeTooLarge`]:     enum.OcidError.html#variant.SizeTooLarge
+    pub fn patch_size_in_place(
+        bytes: &mut [u8],
+        index: usize,
+        new_size: u64,
+    ) -> Result<(), OcidError> {
+        let trailing = bytes.len() % LEN;
+        if trailing != 0 {
+            return Err(OcidError::TrailingBytes(trailing));
+        }
+
+        let len = Self::count_in(bytes);
+        if index >= len {
+            return Err(OcidError::IndexOutOfBounds { index, len });
+        }
+
+        let id = &mut bytes[index * LEN..][..LEN];
+        if id[0] != 0 {
+            return Err(OcidError::UnknownVersion(id[0]));
+        }
+
+        let size = size_bytes_from_u64(new_size)
+            .ok_or(OcidError::SizeTooLarge(new_size))?;
+        id[1..7].copy_from_slice(&size);
+
+        Ok(())
+    }
+
+    /// Splits `ids` into at most `n` subslices of balanced lengths, such as
+    /// for handing to `n` worker threads.
+    ///
+    /// Unlike [`slice::chunks`], `n` is the number of chunks rather than their
+    /// length. Chunk lengths differ by at most 1, with longer chunks first, and
+    /// no chunk is empty. Fewer than `n` chunks are yielded if `ids` has fewer
+    /// than `n` IDs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// [`slice::chunks`]: https://doc.rust-lang.org/std/primitive.slice.html#method.chunks
+    #[inline]
+    pub fn chunks_of(
+        ids: &[OcidV0],
+        n: usize,
+    ) -> impl Iterator<Item = &[OcidV0]> + '_ {
+        assert_ne!(n, 0, "cannot split IDs into 0 chunks");
+
+        let n = n.min(ids.len());
+        let (len, extra) = match n {
+            0 => (0, 0),
+            n => (ids.len() / n, ids.len() % n),
+        };
+
+        (0..n).scan(ids, move |rest, i| {
+            let (chunk, tail) = rest.split_at(len + (i < extra) as usize);
+            *rest = tail;
+            Some(chunk)
+        })
+    }
+
+    /// Returns whether `a` and `b` contain the same IDs, ignoring order and
+    /// duplicates.
+    ///
+    /// If both slices are sorted, this is a linear merge comparison without
+    /// any copying. Otherwise, with the `alloc` feature, sorted copies of the
+    /// slices are compared the same way, taking O(n log n) time. Without
+    /// `alloc`, unsorted slices are compared by searching one for each ID of
+    /// the other, taking O(n * m) time.
+    pub fn slices_set_equal(a: &[OcidV0], b: &[OcidV0]) -> bool {
+        fn is_sorted(ids: &[OcidV0]) -> bool {
+            ids.windows(2).all(|w| w[0] <= w[1])
+        }
+
+        fn sorted_set_equal(a: &[OcidV0], b: &[OcidV0]) -> bool {
+            let (mut i, mut j) = (0, 0);
+            while i < a.len() && j < b.len() {
+                let id = a[i];
+                if b[j] != id {
+                    return false;
+                }
+
+                // Skip duplicates.
+                while i < a.len() && a[i] == id {
+                    i += 1;
+                }
+                while j < b.len() && b[j] == id {
+                    j += 1;
+                }
+            }
+            i == a.len() && j == b.len()
+        }
+
+        if is_sorted(a) && is_sorted(b) {
+            return sorted_set_equal(a, b);
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut a = a.to_vec();
+            let mut b = b.to_vec();
+            a.sort_unstable();
+            b.sort_unstable();
+            sorted_set_equal(&a, &b)
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        {
+            a.iter().all(|id| b.contains(id))
+                && b.iter().all(|id| a.contains(id))
+        }
+    }
+
+    /// Creates an ID that represents an empty file.
+    ///
+    /// This is an all-zero sentinel and is thus not the same as the actual ID
+    /// of empty content, [`EMPTY_CONTENT`](#associatedconstant.EMPTY_CONTENT).
+    #[inline]
+    pub const fn empty() -> OcidV0 {
+        Self::from_parts([0; 6], [0; 32])
+    }
+
+    /// Returns a slice of raw IDs for all of `ids`.
+    #[inline]
+    pub fn slice_as_raw(ids: &[Self]) -> &[RawOcidV0] {
+        let ptr = ids.as_ptr() as *const RawOcidV0;
+        unsafe { slice::from_raw_parts(ptr, ids.len()) }
+    }
+
+    /// Returns a slice of bytes for all of `ids`.
+    #[inline]
+    pub fn slice_as_bytes(ids: &[Self]) -> &[u8] {
+        let ptr = ids.as_ptr() as *const u8;
+        let len = ids.len() * LEN;
+        unsafe { slice::from_raw_parts(ptr, len) }
+    }
+
+    /// Returns the ID version.
+    ///
+    /// In correct code, this always returns 0.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        let version = self.0.version;
+        debug_assert_eq!(version, 0, "{} is not version 0", self);
+        version
+    }
+
+    /// Returns the ID version without asserting that it is 0.
+    ///
+    /// Unlike [`version`](#method.version), this can be used in const contexts.
+    #[inline]
+    pub const fn version_const(&self) -> u8 {
+        self.0.version_const()
+    }
+
+    /// Returns whether the ID is still valid, i.e. if its version is 0.
+    ///
+    /// This can only be `false` if the version was changed through an `unsafe`
+    /// method such as [`as_bytes_mut`](#method.as_bytes_mut), which is a bug.
+    /// It is useful for checking IDs after mutating their raw bytes, such as
+    /// through a reference from [`from_bytes_mut`](#method.from_bytes_mut).
+    #[inline]
+    pub fn revalidate(&self) -> bool {
+        self.version_const() == 0
+    }
+
+    /// Returns the size of the source content as a native integer.
     #[inline]
     pub fn size(&self) -> u64 {
         // SAFETY: The bytes after `size` belong to `hash`. The top 2 bytes are
@@ -359,6 +1255,113 @@ impl OcidV0 {
         &self.0.size
     }
 
+    /// Returns a copy of the size of the source content as big-endian integer
+    /// bytes.
+    ///
+    /// This is the order in which the size is stored within the ID, with the
+    /// most significant byte first. It is the same as the last 6 bytes of
+    /// [`size`](#method.size) converted via [`u64::to_be_bytes`].
+    ///
+    /// [`u64::to_be_bytes`]: https://doc.rust-lang.org/std/primitive.u64.html#method.to_be_bytes
+    #[inline]
+    pub fn size_be_bytes(&self) -> [u8; 6] {
+        self.0.size
+    }
+
+    /// Returns the size of the source content as little-endian integer bytes.
+    ///
+    /// This reverses the canonical big-endian storage order, putting the least
+    /// significant byte first. It is the same as the first 6 bytes of
+    /// [`size`](#method.size) converted via [`u64::to_le_bytes`].
+    ///
+    /// [`u64::to_le_bytes`]: https://doc.rust-lang.org/std/primitive.u64.html#method.to_le_bytes
+    #[inline]
+    pub fn size_le_bytes(&self) -> [u8; 6] {
+        let mut bytes = self.0.size;
+        bytes.reverse();
+        bytes
+    }
+
+    /// Returns the size of the content formatted as a human-readable byte
+    /// count.
+    ///
+    /// Sizes below 1024 are formatted in bytes, such as `"42 B"`. Larger sizes
+    /// are formatted in the largest binary unit (`KiB`, `MiB`, `GiB`, `TiB`)
+    /// that keeps the value below 1024, rounded half up to one decimal place,
+    /// such as `"1.5 KiB"`.
+    #[inline]
+    pub fn size_human(&self) -> impl fmt::Display {
+        struct SizeHuman(u64);
+
+        impl fmt::Display for SizeHuman {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+                let size = self.0;
+                if size < 1024 {
+                    return write!(f, "{} B", size);
+                }
+
+                let mut unit = 1024;
+                for (i, name) in UNITS.iter().enumerate() {
+                    let tenths = (size * 10 + unit / 2) / unit;
+
+                    // Move up a unit if rounding reached 1024.
+                    if tenths < 10240 || i == UNITS.len() - 1 {
+                        return write!(
+                            f,
+                            "{}.{} {}",
+                            tenths / 10,
+                            tenths % 10,
+                            name,
+                        );
+                    }
+
+                    unit *= 1024;
+                }
+
+                unreachable!()
+            }
+        }
+
+        SizeHuman(self.size())
+    }
+
+    /// Returns the number of chunks of `chunk_size` bytes needed to store the
+    /// content, with the last chunk possibly being shorter.
+    ///
+    /// Empty content has no chunks. Returns `None` if `chunk_size` is 0.
+    #[inline]
+    pub fn chunk_count(&self, chunk_size: u64) -> Option<u64> {
+        let size = self.size();
+        let full = size.checked_div(chunk_size)?;
+        Some(full + u64::from(full * chunk_size != size))
+    }
+
+    /// Returns the length of the last chunk when storing the content in chunks
+    /// of `chunk_size` bytes.
+    ///
+    /// This is `chunk_size` if the size of the content is a multiple of it,
+    /// and 0 for empty content. Returns `None` if `chunk_size` is 0.
+    #[inline]
+    pub fn last_chunk_len(&self, chunk_size: u64) -> Option<u64> {
+        let size = self.size();
+        match size.checked_rem(chunk_size)? {
+            0 if size != 0 => Some(chunk_size),
+            rem => Some(rem),
+        }
+    }
+
+    /// Returns whether `data` has the size of the content.
+    ///
+    /// This is a cheap check to reject wrong content, such as a truncated
+    /// download, before hashing it.
+    #[inline]
+    pub fn size_matches(&self, data: &[u8]) -> bool {
+        // Lengths larger than `MAX_SIZE` never match.
+        u64::try_from(data.len()) == Ok(self.size())
+    }
+
     /// Returns whether the content has a size of 0.
     ///
     /// While it is valid for an ID to have a size of 0, it is generally
@@ -377,103 +1380,752 @@ impl OcidV0 {
         &self.0.hash
     }
 
-    /// Returns the result of calling `f` on the [Base64] encoding of the ID.
+    /// Returns the lowercase [hexadecimal] encoding of the [BLAKE3] hash of
+    /// the content.
     ///
-    /// The string passed into `f` is temporarily stack-allocated.
+    /// This drops the size, and thus matches the output of other BLAKE3 tools
+    /// such as `b3sum`.
     ///
-    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    /// [BLAKE3]:      https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    /// [hexadecimal]: https://en.wikipedia.org/wiki/Hexadecimal
     #[inline]
-    pub fn with_base64<F, T>(&self, f: F) -> T
-    where
-        F: for<'b> FnOnce(&'b mut str) -> T,
-    {
-        self.0.with_base64(f)
+    pub fn hash_hex(&self) -> [u8; 64] {
+        let mut hex = [0u8; 64];
+        hex::encode_lower(self.hash(), &mut hex);
+        hex
     }
 
-    /// Writes the [Base64] encoding of the ID to `buf`, returning it as a
-    /// mutable UTF-8 string slice.
+    /// Returns the lowercase [hexadecimal] encoding of all 39 bytes of the ID,
+    /// which is 78 characters long.
     ///
-    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    /// Unlike [`hash_hex`](#method.hash_hex), this includes the version and
+    /// size, and can thus be parsed back with
+    /// [`from_hex_str`](#method.from_hex_str).
+    ///
+    /// [hexadecimal]: https://en.wikipedia.org/wiki/Hexadecimal
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
     #[inline]
-    pub fn encode_base64<'b>(
-        &self,
-        buf: &'b mut [u8; BASE64_LEN],
-    ) -> &'b mut str {
-        self.0.encode_base64(buf)
+    pub fn to_hex_string(&self) -> alloc::string::String {
+        let mut hex = [0u8; LEN * 2];
+        hex::encode_lower(self.as_bytes(), &mut hex);
+
+        match str::from_utf8(&hex) {
+            Ok(hex) => hex.into(),
+            Err(_) => unreachable!("hexadecimal is not UTF-8"),
+        }
     }
 
-    /// Writes the [Base64] encoding of the ID to `buf`, returning it as a
-    /// mutable UTF-8 string slice.
+    /// Returns the first `n` characters of [`hash_hex`](#method.hash_hex) for
+    /// display, such as to show an abbreviated hash.
+    ///
+    /// `n` is capped at 64.
+    #[inline]
+    pub fn hash_hex_prefix(&self, n: usize) -> impl fmt::Display {
+        struct HexPrefix {
+            hex: [u8; 64],
+            len: usize,
+        }
+
+        impl fmt::Display for HexPrefix {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let hex = &self.hex[..self.len];
+                // SAFETY: Hexadecimal is ASCII.
+                unsafe { str::from_utf8_unchecked(hex) }.fmt(f)
+            }
+        }
+
+        HexPrefix {
+            hex: self.hash_hex(),
+            len: n.min(64),
+        }
+    }
+
+    /// Returns a short fingerprint of the ID for display, such as to correlate
+    /// log entries.
+    ///
+    /// This is formatted as the first 8 and last 4 characters of the [Base64]
+    /// encoding separated by an ellipsis (`…`), such as `"--------…6n8X"` for
+    /// [`EMPTY_CONTENT`](#associatedconstant.EMPTY_CONTENT). The first 8
+    /// characters only cover the version and size, so the last 4 characters
+    /// are what distinguish content of the same size.
+    ///
+    /// The fingerprint is **not** parseable back into an ID and may be shared
+    /// by different IDs. Use the [`Display`] implementation wherever the ID
+    /// itself is needed.
+    ///
+    /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
     ///
     /// [Base64]: https://en.wikipedia.org/wiki/Base64
     #[inline]
-    pub fn encode_base64_uninit<'b>(
-        &self,
-        buf: &'b mut [MaybeUninit<u8>; BASE64_LEN],
-    ) -> &'b mut str {
-        self.0.encode_base64_uninit(buf)
+    pub fn short(&self) -> impl fmt::Display {
+        struct Short(OcidV0);
+
+        impl fmt::Display for Short {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.with_base64(|b64| {
+                    let (head, tail) = (&b64[..8], &b64[BASE64_LEN - 4..]);
+                    write!(f, "{}\u{2026}{}", head, tail)
+                })
+            }
+        }
+
+        Short(*self)
     }
 
-    /// Returns a shared reference to the body of the ID, i.e. everything after
-    /// the version number.
+    /// Returns the ID formatted alongside its size in decimal, such as for
+    /// debugging a catalog.
+    ///
+    /// This is formatted as `"<base64> (<n> bytes)"`, or `"<base64> (1 byte)"`
+    /// when the size is 1, where `<n>` is [`size`](#method.size).
     #[inline]
-    pub fn body(&self) -> &[u8; LEN - 1] {
-        unsafe { &*(self.0.size.as_ptr() as *const _) }
+    pub fn display_with_size(&self) -> impl fmt::Display {
+        struct DisplayWithSize(OcidV0);
+
+        impl fmt::Display for DisplayWithSize {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match self.0.size() {
+                    1 => write!(f, "{} (1 byte)", self.0),
+                    size => write!(f, "{} ({} bytes)", self.0, size),
+                }
+            }
+        }
+
+        DisplayWithSize(*self)
     }
 
-    /// Returns a mutable reference to the body of the ID, i.e. everything after
-    /// the version number.
+    /// Returns a stable 64-bit key for sharding, taken from the first 8 bytes
+    /// of the [BLAKE3] hash as a big-endian integer.
+    ///
+    /// Unlike hashing with a randomly-seeded hasher, this key is the same
+    /// across runs, which makes it suitable for consistent hashing and bucket
+    /// assignment. It is for distribution only: different IDs may share a key,
+    /// so it must not be used for deduplication.
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
     #[inline]
-    pub fn body_mut(&mut self) -> &mut [u8; LEN - 1] {
-        unsafe { &mut *(self.0.size.as_mut_ptr() as *mut _) }
+    pub fn shard_key(&self) -> u64 {
+        let mut key = [0u8; 8];
+        key.copy_from_slice(&self.0.hash[..8]);
+        u64::from_be_bytes(key)
     }
 
-    /// Converts `self` into a raw ID.
+    /// Returns a 64-bit value derived from the [BLAKE3] hash, for use as a
+    /// precomputed key in maps with an identity hasher, such as
+    /// [`nohash-hasher`].
+    ///
+    /// This folds the 32-byte hash into 64 bits by XOR-ing its 8-byte
+    /// little-endian words. Because the hash output is uniformly random, the
+    /// result is as well, so re-hashing it is unnecessary.
+    ///
+    /// This is derived from the content hash only, not the size. IDs that
+    /// differ only in size, which indicates a
+    /// [conflict](#method.conflicts_with), have the same value. Map keys must
+    /// still be compared with [`Eq`].
+    ///
+    /// [`Eq`]:            https://doc.rust-lang.org/std/cmp/trait.Eq.html
+    /// [`nohash-hasher`]: https://docs.rs/nohash-hasher
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
     #[inline]
-    pub fn into_raw(self) -> RawOcidV0 {
-        self.0
+    pub fn identity_u64(&self) -> u64 {
+        self.0.hash.chunks_exact(8).fold(0, |acc, word| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(word);
+            acc ^ u64::from_le_bytes(bytes)
+        })
     }
 
-    /// Returns a shared reference to the raw ID.
+    /// Returns whether `self` and `other` have the same [BLAKE3] hash,
+    /// regardless of their sizes.
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
     #[inline]
-    pub fn as_raw(&self) -> &RawOcidV0 {
-        &self.0
+    pub fn same_hash(&self, other: &Self) -> bool {
+        self.0.hash == other.0.hash
     }
 
-    /// Returns a mutable reference to the raw ID.
+    /// Returns the number of leading characters shared by the [Base64]
+    /// encodings of `self` and `other`.
     ///
-    /// # Safety
+    /// Because the Base64 alphabet is sorted, this is the depth at which the
+    /// two IDs diverge in a radix tree over their encodings. Both encodings
+    /// are written to stack buffers, so this does not allocate.
     ///
-    /// Care must be taken to not change the version from 0.
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
     #[inline]
-    pub unsafe fn as_raw_mut(&mut self) -> &mut RawOcidV0 {
-        &mut self.0
+    pub fn base64_common_prefix_len(&self, other: &Self) -> usize {
+        let mut a = [MaybeUninit::uninit(); BASE64_LEN];
+        let mut b = [MaybeUninit::uninit(); BASE64_LEN];
+
+        let a = self.encode_base64_uninit(&mut a).as_bytes();
+        let b = other.encode_base64_uninit(&mut b).as_bytes();
+
+        a.iter().zip(b).take_while(|(a, b)| a == b).count()
     }
 
-    /// Returns the ID as its bytes.
+    /// Returns the length of the shortest [Base64] prefix of `target` that is
+    /// not shared by any other ID in `sorted`, like abbreviated commit hashes
+    /// in Git.
+    ///
+    /// Because the Base64 alphabet is sorted, only the neighbors of `target`
+    /// in `sorted` need to be compared, which takes O(log n) time. Copies of
+    /// `target` in `sorted` are ignored, and `target` need not be in `sorted`.
+    /// The result is at least 1. If `sorted` is not sorted, the result is
+    /// unspecified.
+    ///
+    /// Note that the first 8 characters of any ID only encode its version and
+    /// size, so prefixes often need to be longer than that.
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    pub fn shortest_unique_prefix_len(
+        sorted: &[OcidV0],
+        target: &OcidV0,
+    ) -> usize {
+        let start = sorted.partition_point(|id| id < target);
+        let end = start + sorted[start..].partition_point(|id| id == target);
+
+        let shared = sorted[..start]
+            .last()
+            .into_iter()
+            .chain(sorted.get(end))
+            .map(|id| target.base64_common_prefix_len(id))
+            .max()
+            .unwrap_or(0);
+
+        shared + 1
+    }
+
+    /// Returns the number of bits that differ between the [BLAKE3] hashes of
+    /// `self` and `other`.
+    ///
+    /// This is intended for research and diagnostics only. BLAKE3 is not
+    /// locality-sensitive, so similar content does not produce hashes with a
+    /// small distance. Any differing content results in about 128 differing
+    /// bits on average.
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
     #[inline]
-    pub fn into_bytes(self) -> [u8; LEN] {
-        self.into_raw().into_bytes()
+    pub fn hash_hamming_distance(&self, other: &Self) -> u32 {
+        self.0
+            .hash
+            .chunks_exact(8)
+            .zip(other.0.hash.chunks_exact(8))
+            .map(|(a, b)| {
+                let mut a_word = [0u8; 8];
+                let mut b_word = [0u8; 8];
+                a_word.copy_from_slice(a);
+                b_word.copy_from_slice(b);
+                (u64::from_ne_bytes(a_word) ^ u64::from_ne_bytes(b_word))
+                    .count_ones()
+            })
+            .sum()
     }
 
-    /// Returns a shared reference to the bytes of the ID.
+    /// Returns both the ordering of `self` relative to `other` and whether
+    /// they are equal, such as for search loops over a sorted index that need
+    /// to know where an ID goes and whether it was found.
+    ///
+    /// This compares the IDs once, whereas calling [`cmp`] followed by `==`
+    /// compares them twice. The saving measured by `benches/cmp_then_eq.rs`
+    /// is small and varies between runs, so this is mostly a convenience.
+    /// Equality is derived from the ordering, since IDs are only equal if
+    /// neither sorts before the other.
+    ///
+    /// [`cmp`]: https://doc.rust-lang.org/std/cmp/trait.Ord.html#tymethod.cmp
     #[inline]
-    pub fn as_bytes(&self) -> &[u8; LEN] {
-        self.as_raw().as_bytes()
+    pub fn cmp_then_eq(&self, other: &Self) -> (cmp::Ordering, bool) {
+        let ordering = self.cmp(other);
+        (ordering, ordering == cmp::Ordering::Equal)
     }
 
-    /// Returns a mutable reference to the bytes of the ID.
+    /// Returns whether `self` and `other` have the same [BLAKE3] hash but
+    /// different sizes.
     ///
-    /// # Safety
+    /// This should never happen for IDs of the same content, and thus indicates
+    /// either corrupted size metadata or a hash collision.
     ///
-    /// Care must be taken to not change the version from 0.
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
     #[inline]
-    pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8; LEN] {
-        self.as_raw_mut().as_bytes_mut()
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        self.same_hash(other) && self.0.size != other.0.size
     }
-}
 
-#[cfg(test)]
+    /// Returns whether `self` and `other` are equal or either is the all-zero
+    /// [`empty`](#method.empty) sentinel, for when the sentinel means
+    /// "unknown".
+    ///
+    /// Only the sentinel is unknown. The ID of empty content,
+    /// [`EMPTY_CONTENT`](#associatedconstant.EMPTY_CONTENT), also has a size
+    /// of 0 but only matches itself.
+    ///
+    /// This is deliberately not transitive: the sentinel matches any two IDs
+    /// that do not match each other. It must not be used where [`Eq`]
+    /// semantics are required, such as for deduplication or as a map key.
+    ///
+    /// [`Eq`]: https://doc.rust-lang.org/std/cmp/trait.Eq.html
+    #[inline]
+    pub fn matches_or_unknown(&self, other: &Self) -> bool {
+        let unknown = Self::empty();
+        self == other || *self == unknown || *other == unknown
+    }
+
+    /// Returns the size and [BLAKE3] hash of the content.
+    ///
+    /// This is the inverse of [`from_parts_u64`](#method.from_parts_u64).
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[inline]
+    pub fn into_parts(self) -> (u64, [u8; 32]) {
+        (self.size(), self.0.hash)
+    }
+
+    /// Returns the size of the content and a reference to its [BLAKE3] hash.
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[inline]
+    pub fn parts(&self) -> (u64, &[u8; 32]) {
+        (self.size(), &self.0.hash)
+    }
+
+    /// Returns references to the version, size, and [BLAKE3] hash fields of
+    /// the ID, in the order they are laid out in memory.
+    ///
+    /// The size is in its [big-endian] form, as in
+    /// [`size_bytes`](#method.size_bytes).
+    ///
+    /// [big-endian]: https://en.wikipedia.org/wiki/Endianness#Big-endian
+    /// [BLAKE3]:     https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[inline]
+    pub fn as_field_slices(&self) -> (&u8, &[u8; 6], &[u8; 32]) {
+        (&self.0.version, &self.0.size, &self.0.hash)
+    }
+
+    /// Returns the result of calling `f` on the [Base64] encoding of the ID.
+    ///
+    /// The string passed into `f` is temporarily stack-allocated.
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    #[inline]
+    pub fn with_base64<F, T>(&self, f: F) -> T
+    where
+        F: for<'b> FnOnce(&'b mut str) -> T,
+    {
+        self.0.with_base64(f)
+    }
+
+    /// Returns the [Base64] encoding of the ID as a heap-allocated string.
+    ///
+    /// Unlike [`ToString::to_string`], this is available without `std`.
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    /// [`ToString::to_string`]: https://doc.rust-lang.org/std/string/trait.ToString.html#tymethod.to_string
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn to_base64_string(&self) -> alloc::string::String {
+        self.with_base64(|b64| alloc::string::String::from(&*b64))
+    }
+
+    /// Returns the [Base64] encoding of the ID as a [`Cow`], for APIs that
+    /// return either borrowed or owned strings.
+    ///
+    /// Encoding always produces a new string, so this is always
+    /// [`Cow::Owned`]. To avoid allocating, encode into a reused buffer with
+    /// [`encode_base64`](#method.encode_base64) instead.
+    ///
+    /// [`Cow`]:        https://doc.rust-lang.org/std/borrow/enum.Cow.html
+    /// [`Cow::Owned`]: https://doc.rust-lang.org/std/borrow/enum.Cow.html#variant.Owned
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn to_base64_cow(&self) -> alloc::borrow::Cow<'static, str> {
+        alloc::borrow::Cow::Owned(self.to_base64_string())
+    }
+
+    /// Returns the [Base64] encoding of the ID followed by a checksum
+    /// character.
+    ///
+    /// The checksum catches any single mistyped character, as well as most
+    /// swaps of adjacent characters. This form is not accepted by
+    /// [`FromStr`]; use [`parse_checked`] instead.
+    ///
+    /// [Base64]:          https://en.wikipedia.org/wiki/Base64
+    /// [`FromStr`]:       https://doc.rust-lang.org/std/str/trait.FromStr.html
+    /// [`parse_checked`]: #method.parse_checked
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn to_string_checked(&self) -> alloc::string::String {
+        let mut buf = [0u8; BASE64_LEN];
+        let b64 = self.encode_base64(&mut buf);
+
+        let mut string = alloc::string::String::with_capacity(BASE64_LEN + 1);
+        string.push_str(b64);
+        string.push(base64::checksum_39(&buf) as char);
+        string
+    }
+
+    /// Returns the ID as a segment for URL paths and query values, such as in
+    /// `/packages/<segment>/`.
+    ///
+    /// ```
+    /// # use ocid::OcidV0;
+    /// let id = OcidV0::new(b"hello, world").unwrap();
+    /// assert_eq!(
+    ///     id.to_url_segment(),
+    ///     "v0--------295_L7SIJtTzGWZ1HGm6TGX9MS6sktJQEOnqvAcOIcTk",
+    /// );
+    /// ```
+    ///
+    /// This is the [Base64] encoding prefixed by `v0`, and is thus 54
+    /// characters long. It is guaranteed to:
+    ///
+    /// - Only contain the characters `A-Z`, `a-z`, `0-9`, `-`, and `_`, which
+    ///   are unreserved in [RFC 3986] and thus never percent-encoded.
+    /// - Start with a letter, unlike the Base64 encoding, which always starts
+    ///   with `-` and can be mistaken for a flag or a range.
+    /// - Never be `.` or `..`, nor contain `/`, `?`, `#`, or `&`.
+    ///
+    /// The ID can be parsed back by removing the first 2 characters and using
+    /// the [`FromStr`] implementation.
+    ///
+    /// [Base64]:    https://en.wikipedia.org/wiki/Base64
+    /// [RFC 3986]:  https://tools.ietf.org/html/rfc3986#section-2.3
+    /// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn to_url_segment(&self) -> alloc::string::String {
+        self.prefixed_base64("v0")
+    }
+
+    /// Returns the ID as a URL fragment for linking to content in web apps.
+    ///
+    /// This is the [Base64] encoding prefixed by `#ocid=`. Because the
+    /// alphabet is URL-safe, the encoding needs no escaping. To use it as a
+    /// query parameter instead, replace the leading `#` with `?` or `&`.
+    ///
+    /// ```
+    /// # use ocid::OcidV0;
+    /// let id = OcidV0::new(b"hello, world").unwrap();
+    /// assert_eq!(
+    ///     id.to_data_fragment(),
+    ///     "#ocid=--------295_L7SIJtTzGWZ1HGm6TGX9MS6sktJQEOnqvAcOIcTk",
+    /// );
+    /// ```
+    ///
+    /// This is distinct from the `ocid:` prefix accepted by
+    /// [`parse_lenient`](#method.parse_lenient).
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn to_data_fragment(&self) -> alloc::string::String {
+        self.prefixed_base64("#ocid=")
+    }
+
+    /// Returns the [Base64](https://en.wikipedia.org/wiki/Base64) encoding of
+    /// the ID prefixed by `prefix`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn prefixed_base64(&self, prefix: &str) -> alloc::string::String {
+        let mut buf = [0u8; BASE64_LEN];
+        let b64 = self.encode_base64(&mut buf);
+
+        let mut string =
+            alloc::string::String::with_capacity(prefix.len() + BASE64_LEN);
+        string.push_str(prefix);
+        string.push_str(b64);
+        string
+    }
+
+    /// Returns a relative path for storing content by its ID, fanned out into
+    /// nested directories.
+    ///
+    /// The first `levels * chars_per_level` characters of the [Base64]
+    /// encoding are split into `levels` directories, and the rest of the
+    /// characters make up the file name. For example, 2 levels of 2 characters
+    /// results in `ab/cd/<rest>`.
+    ///
+    /// The file name always keeps at least one character. If
+    /// `levels * chars_per_level` is 52 or more, only as many levels as fit are
+    /// used.
+    ///
+    /// Note that Base64 is case-sensitive, and so these paths should not be
+    /// used on case-insensitive file systems.
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn to_path_buf(
+        &self,
+        levels: usize,
+        chars_per_level: usize,
+    ) -> std::path::PathBuf {
+        let levels = match (BASE64_LEN - 1).checked_div(chars_per_level) {
+            Some(max_levels) => levels.min(max_levels),
+            None => 0,
+        };
+
+        self.with_base64(|b64| {
+            let mut path = std::path::PathBuf::new();
+            let mut rest: &str = b64;
+
+            for _ in 0..levels {
+                let (dir, tail) = rest.split_at(chars_per_level);
+                path.push(dir);
+                rest = tail;
+            }
+
+            path.push(rest);
+            path
+        })
+    }
+
+    /// Writes the [Base64] encoding of the ID to `buf`, returning it as a
+    /// mutable UTF-8 string slice.
+    ///
+    /// When encoding many IDs, the same buffer of
+    /// [`BASE64_LEN`](#associatedconstant.BASE64_LEN) bytes can be reused for
+    /// each one to avoid any allocation:
+    ///
+    /// ```
+    /// # use ocid::OcidV0;
+    /// # let ids = [OcidV0::empty(); 2];
+    /// let mut buf = [0u8; OcidV0::BASE64_LEN];
+    /// for id in &ids {
+    ///     let b64: &str = id.encode_base64(&mut buf);
+    ///     assert_eq!(b64.len(), OcidV0::BASE64_LEN);
+    /// }
+    /// ```
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    #[inline]
+    pub fn encode_base64<'b>(
+        &self,
+        buf: &'b mut [u8; BASE64_LEN],
+    ) -> &'b mut str {
+        self.0.encode_base64(buf)
+    }
+
+    /// Writes the [Base64] encoding of the ID to `buf`, returning it as a
+    /// mutable UTF-8 string slice.
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    #[inline]
+    pub fn encode_base64_uninit<'b>(
+        &self,
+        buf: &'b mut [MaybeUninit<u8>; BASE64_LEN],
+    ) -> &'b mut str {
+        self.0.encode_base64_uninit(buf)
+    }
+
+    /// Returns a shared reference to the body of the ID, i.e. everything after
+    /// the version number.
+    #[inline]
+    pub fn body(&self) -> &[u8; LEN - 1] {
+        unsafe { &*(self.0.size.as_ptr() as *const _) }
+    }
+
+    /// Returns a mutable reference to the body of the ID, i.e. everything after
+    /// the version number.
+    ///
+    /// Because the version is not part of the body, mutating it cannot make the
+    /// ID invalid. Prefer [`set_size`] and [`set_hash`] when changing a single
+    /// component.
+    ///
+    /// [`set_size`]: #method.set_size
+    /// [`set_hash`]: #method.set_hash
+    #[inline]
+    pub fn body_mut(&mut self) -> &mut [u8; LEN - 1] {
+        unsafe { &mut *(self.0.size.as_mut_ptr() as *mut _) }
+    }
+
+    /// Sets the size of the content.
+    ///
+    /// Returns `false` and leaves the ID unchanged if `size` is larger than
+    /// [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    #[inline]
+    pub fn set_size(&mut self, size: u64) -> bool {
+        match size_bytes_from_u64(size) {
+            Some(size) => {
+                self.0.size = size;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets the [BLAKE3] hash of the content.
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[inline]
+    pub fn set_hash(&mut self, hash: [u8; 32]) {
+        self.0.hash = hash;
+    }
+
+    /// Converts `self` into the version-agnostic [`Ocid`](enum.Ocid.html).
+    #[inline]
+    pub fn into_ocid(self) -> Ocid {
+        Ocid::V0 {
+            size: self.0.size,
+            hash: self.0.hash,
+        }
+    }
+
+    /// Converts `self` into a raw ID.
+    #[inline]
+    pub fn into_raw(self) -> RawOcidV0 {
+        self.0
+    }
+
+    /// Returns a shared reference to the raw ID.
+    #[inline]
+    pub fn as_raw(&self) -> &RawOcidV0 {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the raw ID.
+    ///
+    /// # Safety
+    ///
+    /// Care must be taken to not change the version from 0.
+    #[inline]
+    pub unsafe fn as_raw_mut(&mut self) -> &mut RawOcidV0 {
+        &mut self.0
+    }
+
+    /// Returns the canonical wire format of the ID.
+    ///
+    /// This is a fixed-width format without any framing, made up of:
+    ///
+    /// | Component | Offset | Size | Description
+    /// | :-------- | :----- | :--- | :----------
+    /// | Version   | 0      |  1   | Always zero
+    /// | Size      | 1      |  6   | [Big-endian] content size
+    /// | Hash      | 7      | 32   | [BLAKE3] content hash
+    ///
+    /// This is the same as the memory representation and is thus equivalent to
+    /// [`into_bytes`](#method.into_bytes).
+    ///
+    /// [Big-endian]: https://en.wikipedia.org/wiki/Endianness#Big-endian
+    /// [BLAKE3]:     https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    #[inline]
+    pub fn to_wire(&self) -> [u8; LEN] {
+        self.into_bytes()
+    }
+
+    /// Returns the bytes of the ID packed into integers, such as for storing
+    /// in atomics or SIMD registers.
+    ///
+    /// # Layout
+    ///
+    /// The 39 bytes of the ID are read 8 at a time as [big-endian] integers,
+    /// with the last integer holding the final 7 bytes in its upper bits and a
+    /// zero padding byte in its lowest 8 bits:
+    ///
+    /// | Index | Bytes
+    /// | :---- | :----
+    /// | 0     | 0..8
+    /// | 1     | 8..16
+    /// | 2     | 16..24
+    /// | 3     | 24..32
+    /// | 4     | 32..39, then padding
+    ///
+    /// Because of this layout, comparing the arrays gives the same order as
+    /// comparing the IDs.
+    ///
+    /// [big-endian]: https://en.wikipedia.org/wiki/Endianness#Big-endian
+    #[inline]
+    pub fn to_u64_array(&self) -> [u64; 5] {
+        let mut bytes = [0u8; 40];
+        bytes[..LEN].copy_from_slice(self.as_bytes());
+
+        let mut array = [0u64; 5];
+        for (int, chunk) in array.iter_mut().zip(bytes.chunks_exact(8)) {
+            let mut int_bytes = [0u8; 8];
+            int_bytes.copy_from_slice(chunk);
+            *int = u64::from_be_bytes(int_bytes);
+        }
+        array
+    }
+
+    /// Returns the bytes of the ID packed into 32-bit words, such as for
+    /// vectorized routines that operate on `u32` lanes.
+    ///
+    /// # Layout
+    ///
+    /// The 39 bytes of the ID are read 4 at a time as [big-endian] integers,
+    /// with the last word holding the final 3 bytes in its upper bits and a
+    /// zero padding byte in its lowest 8 bits. Thus word `i` holds bytes
+    /// `4 * i..4 * i + 4`.
+    ///
+    /// As with [`to_u64_array`](#method.to_u64_array), comparing the arrays
+    /// gives the same order as comparing the IDs.
+    ///
+    /// [big-endian]: https://en.wikipedia.org/wiki/Endianness#Big-endian
+    #[inline]
+    pub fn as_u32_words(&self) -> [u32; 10] {
+        let mut bytes = [0u8; 40];
+        bytes[..LEN].copy_from_slice(self.as_bytes());
+
+        let mut words = [0u32; 10];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+            let mut word_bytes = [0u8; 4];
+            word_bytes.copy_from_slice(chunk);
+            *word = u32::from_be_bytes(word_bytes);
+        }
+        words
+    }
+
+    /// Returns the ID as its bytes.
+    #[inline]
+    pub fn into_bytes(self) -> [u8; LEN] {
+        self.into_raw().into_bytes()
+    }
+
+    /// Returns a shared reference to the bytes of the ID.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; LEN] {
+        self.as_raw().as_bytes()
+    }
+
+    /// Returns the bytes of the ID for use as a key in an ordered key-value
+    /// store, such as [sled] or [RocksDB].
+    ///
+    /// This is the same as [`as_bytes`](#method.as_bytes). Because IDs have a
+    /// [lexicographical order] by their bytes, the order of these keys matches
+    /// [`Ord`]: range scans visit IDs by content size, then by hash. As a
+    /// result, a scan over the keys prefixed by a [`size_bytes`] value yields
+    /// all stored IDs of content with that size.
+    ///
+    /// [`Ord`]:        https://doc.rust-lang.org/std/cmp/trait.Ord.html
+    /// [`size_bytes`]: #method.size_bytes
+    ///
+    /// [lexicographical order]: https://en.wikipedia.org/wiki/Lexicographical_order
+    /// [RocksDB]:               https://rocksdb.org
+    /// [sled]:                  https://docs.rs/sled
+    #[inline]
+    pub fn as_db_key(&self) -> &[u8; LEN] {
+        self.as_bytes()
+    }
+
+    /// Returns a mutable reference to the bytes of the ID.
+    ///
+    /// # Safety
+    ///
+    /// Care must be taken to not change the version from 0.
+    #[inline]
+    pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8; LEN] {
+        self.as_raw_mut().as_bytes_mut()
+    }
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
     use rand_core::RngCore;
@@ -495,4 +2147,1262 @@ mod tests {
             assert_eq!(id.size(), size_u64);
         }
     }
+
+    #[test]
+    fn empty_content() {
+        assert_eq!(OcidV0::new(b""), Some(OcidV0::EMPTY_CONTENT));
+        assert_ne!(OcidV0::EMPTY_CONTENT, OcidV0::empty());
+        assert!(OcidV0::EMPTY_CONTENT.is_empty());
+    }
+
+    #[test]
+    fn new_from() {
+        let id = OcidV0::new(b"hello");
+
+        let vec = b"hello".to_vec();
+
+        assert_eq!(OcidV0::new_from("hello"), id);
+        assert_eq!(OcidV0::new_from(String::from("hello")), id);
+        assert_eq!(OcidV0::new_from(vec), id);
+        assert_eq!(OcidV0::new_from(b"hello"), id);
+    }
+
+    #[test]
+    fn from_blake3() {
+        let content = b"hello, world";
+        let hash = blake3::hash(content);
+
+        assert_eq!(OcidV0::from_blake3(hash, 12), OcidV0::new(content));
+        assert_eq!(OcidV0::from_blake3(hash, OcidV0::MAX_SIZE + 1), None);
+    }
+
+    #[test]
+    fn new_chunks() {
+        let chunks: &[&[u8]] = &[b"hello", b"", b", ", b"world"];
+
+        assert_eq!(OcidV0::new_chunks(chunks), OcidV0::new(b"hello, world"));
+        assert_eq!(OcidV0::new_chunks(Vec::<Vec<u8>>::new()), OcidV0::new(b""));
+    }
+
+    #[test]
+    fn parent_of() {
+        let children = [
+            OcidV0::from_parts_u64(1, [0x11; 32]).unwrap(),
+            OcidV0::from_parts_u64(20, [0x22; 32]).unwrap(),
+            OcidV0::from_parts_u64(300, [0x33; 32]).unwrap(),
+        ];
+
+        let parent = OcidV0::parent_of(&children).unwrap();
+        assert_eq!(
+            &parent.hash_hex()[..],
+            &b"c1d629c1fd2305533b7c0469cc68996944a8b9ce6a473f9195ed0f59aee7f9ab"[..],
+        );
+        assert_eq!(parent.size(), 321);
+        assert_eq!(OcidV0::parent_of(&children), Some(parent));
+
+        let bytes = OcidV0::slice_as_bytes(&children);
+        assert_eq!(parent.hash(), blake3::hash(bytes).as_bytes());
+
+        // Order matters.
+        let reversed = [children[2], children[1], children[0]];
+        let reversed = OcidV0::parent_of(&reversed).unwrap();
+        assert_eq!(reversed.size(), parent.size());
+        assert_ne!(reversed, parent);
+
+        assert_eq!(OcidV0::parent_of(&[]), OcidV0::new(b""));
+
+        let max = OcidV0::from_parts_u64(OcidV0::MAX_SIZE, [0; 32]).unwrap();
+        assert_eq!(OcidV0::parent_of(&[max, max]), None);
+        assert_eq!(OcidV0::parent_of(&[max, children[0]]), None);
+    }
+
+    #[test]
+    fn new_with() {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(b"stale state");
+
+        for content in &[&b""[..], b"hello", b"hello, world"] {
+            let id = OcidV0::new_with(&mut hasher, content);
+            assert_eq!(id, OcidV0::new(content));
+        }
+    }
+
+    #[test]
+    fn concat_hash() {
+        let a = b"name=ocean\n";
+        let b = b"version=1.0.0\n";
+
+        let mut concat = a.to_vec();
+        concat.extend_from_slice(b);
+
+        assert_eq!(OcidV0::concat_hash(&[a, b]), OcidV0::new(&concat));
+        assert_eq!(OcidV0::concat_hash(&[]), OcidV0::new(b""));
+    }
+
+    #[test]
+    fn from_seed() {
+        // Values must never change across versions or platforms.
+        let id = OcidV0::from_seed(0);
+        assert_eq!(id.size(), 184_964_832_210_896);
+        assert_eq!(
+            &id.hash_hex()[..],
+            &b"f465b9a16a9e786e4f450980185dc406ec814c72a8b88bf89b74a8516a89391b"[..],
+        );
+
+        for seed in 0..1000 {
+            let id = OcidV0::from_seed(seed);
+            assert!(!id.is_empty());
+            assert_eq!(OcidV0::from_seed(seed), id);
+            assert_ne!(OcidV0::from_seed(seed + 1), id);
+        }
+    }
+
+    #[test]
+    fn rand_raw() {
+        // An RNG that only emits zeros.
+        struct Zeros;
+
+        impl RngCore for Zeros {
+            fn next_u32(&mut self) -> u32 {
+                0
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                0
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                dest.iter_mut().for_each(|b| *b = 0);
+            }
+
+            fn try_fill_bytes(
+                &mut self,
+                dest: &mut [u8],
+            ) -> Result<(), rand_core::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+
+        assert_eq!(OcidV0::rand_raw(Zeros), OcidV0::empty());
+        assert_ne!(OcidV0::rand_raw(rand_core::OsRng), OcidV0::empty());
+    }
+
+    #[test]
+    fn version_const() {
+        const ID: OcidV0 = OcidV0::empty();
+        const VERSION: u8 = ID.version_const();
+
+        assert_eq!(VERSION, 0);
+        assert_eq!(OcidV0::rand(rand_core::OsRng).version_const(), 0);
+    }
+
+    #[test]
+    fn max_size() {
+        let max = OcidV0::MAX_SIZE;
+
+        assert!(OcidV0::size_fits(max));
+        assert!(!OcidV0::size_fits(max + 1));
+
+        let id = OcidV0::from_parts_u64(max, [0; 32]).unwrap();
+        assert_eq!(id.size(), max);
+
+        assert_eq!(OcidV0::from_parts_u64(max + 1, [0; 32]), None);
+    }
+
+    #[test]
+    fn from_legacy_hash() {
+        let content = b"hello, world";
+        let legacy: [u8; 32] = blake3::hash(content).into();
+
+        let id = OcidV0::from_legacy_hash(legacy, content.len() as u64);
+        assert_eq!(id, OcidV0::new(content));
+        assert_eq!(OcidV0::from_legacy_hash(legacy, u64::MAX), None);
+    }
+
+    #[test]
+    fn setters() {
+        let mut bytes =
+            OcidV0::from_parts_u64(1, [1; 32]).unwrap().into_bytes();
+        let id = OcidV0::from_bytes_mut(&mut bytes).unwrap();
+
+        assert!(id.set_size(2));
+        id.set_hash([2; 32]);
+        assert_eq!(id.parts(), (2, &[2; 32]));
+
+        assert!(!id.set_size(OcidV0::MAX_SIZE + 1));
+        assert_eq!(id.size(), 2);
+
+        id.body_mut().copy_from_slice(&[0xFF; LEN - 1]);
+        assert!(id.revalidate());
+
+        unsafe { id.as_bytes_mut()[0] = 1 };
+        assert!(!id.revalidate());
+        assert_eq!(bytes[0], 1);
+    }
+
+    #[test]
+    fn size_endian_bytes() {
+        let size = 0x0102_0304_0506;
+        let id = OcidV0::from_parts_u64(size, [0; 32]).unwrap();
+
+        let be = id.size_be_bytes();
+        let le = id.size_le_bytes();
+        assert_eq!(be, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(le, [6, 5, 4, 3, 2, 1]);
+
+        let mut be_u64 = [0; 8];
+        be_u64[2..].copy_from_slice(&be);
+        assert_eq!(u64::from_be_bytes(be_u64), id.size());
+
+        let mut le_u64 = [0; 8];
+        le_u64[..6].copy_from_slice(&le);
+        assert_eq!(u64::from_le_bytes(le_u64), id.size());
+
+        assert_eq!(OcidV0::from_parts(be, [0; 32]), id);
+    }
+
+    #[test]
+    fn size_human() {
+        let cases: &[(u64, &str)] = &[
+            (0, "0 B"),
+            (1023, "1023 B"),
+            (1024, "1.0 KiB"),
+            (1536, "1.5 KiB"),
+            (1024 * 1024 - 1, "1.0 MiB"),
+            (5 * 1024 * 1024 * 1024 + 1, "5.0 GiB"),
+            (OcidV0::MAX_SIZE, "256.0 TiB"),
+        ];
+
+        for &(size, expected) in cases {
+            let id = OcidV0::from_parts_u64(size, [0; 32]).unwrap();
+            assert_eq!(id.size_human().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn chunks() {
+        let id = |size| OcidV0::from_parts_u64(size, [0; 32]).unwrap();
+
+        let cases = [
+            (0, 4, 0, 0),
+            (1, 4, 1, 1),
+            (4, 4, 1, 4),
+            (5, 4, 2, 1),
+            (8, 4, 2, 4),
+            (9, 1, 9, 1),
+            (3, 10, 1, 3),
+            (OcidV0::MAX_SIZE, 1 << 20, 1 << 28, (1 << 20) - 1),
+        ];
+        for &(size, chunk_size, count, last) in &cases {
+            assert_eq!(id(size).chunk_count(chunk_size), Some(count));
+            assert_eq!(id(size).last_chunk_len(chunk_size), Some(last));
+        }
+
+        assert_eq!(id(5).chunk_count(0), None);
+        assert_eq!(id(5).last_chunk_len(0), None);
+        assert_eq!(id(0).chunk_count(0), None);
+    }
+
+    #[test]
+    fn size_matches() {
+        let content = [0u8; 100];
+        let id = OcidV0::new(&content).unwrap();
+
+        assert!(id.size_matches(&content));
+        assert!(!id.size_matches(&content[1..]));
+        assert!(!id.size_matches(&[]));
+        assert!(OcidV0::EMPTY_CONTENT.size_matches(&[]));
+    }
+
+    #[test]
+    fn parts() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..64 {
+            let id = OcidV0::rand(&mut rng);
+
+            let (size, hash) = id.parts();
+            assert_eq!((size, *hash), id.into_parts());
+
+            let (size, hash) = id.into_parts();
+            assert_eq!(OcidV0::from_parts_u64(size, hash), Some(id));
+        }
+    }
+
+    #[test]
+    fn as_field_slices() {
+        let id = OcidV0::from_parts_u64(0x0102_0304_0506, [0xAA; 32]).unwrap();
+        let (version, size, hash) = id.as_field_slices();
+
+        assert_eq!(*version, 0);
+        assert_eq!(size, &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(hash, &[0xAA; 32]);
+
+        let bytes = id.as_bytes().as_ptr();
+        assert_eq!(version as *const u8, bytes);
+        assert_eq!(size.as_ptr(), bytes.wrapping_add(1));
+        assert_eq!(hash.as_ptr(), bytes.wrapping_add(7));
+    }
+
+    #[test]
+    fn hash_hex() {
+        let hex = OcidV0::EMPTY_CONTENT.hash_hex();
+        assert_eq!(
+            str::from_utf8(&hex).unwrap(),
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262",
+        );
+
+        let prefix = OcidV0::EMPTY_CONTENT.hash_hex_prefix(7).to_string();
+        assert_eq!(prefix, "af1349b");
+
+        let prefix = OcidV0::EMPTY_CONTENT.hash_hex_prefix(100).to_string();
+        assert_eq!(prefix.as_bytes(), &hex[..]);
+    }
+
+    #[test]
+    fn short() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..16 {
+            let id = OcidV0::rand(&mut rng);
+            let full = id.to_string();
+            let short = id.short().to_string();
+
+            assert_eq!(short.chars().count(), 13);
+            assert_eq!(short[..8], full[..8]);
+            assert!(short.ends_with(&full[BASE64_LEN - 4..]));
+            assert_eq!(short[8..].chars().next(), Some('\u{2026}'));
+        }
+
+        let short = OcidV0::EMPTY_CONTENT.short().to_string();
+        assert_eq!(short, "--------\u{2026}6n8X");
+    }
+
+    #[test]
+    fn display_with_size() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..16 {
+            let id = OcidV0::rand(&mut rng);
+            let expected = format!("{} ({} bytes)", id, id.size());
+            assert_eq!(id.display_with_size().to_string(), expected);
+        }
+
+        let id = OcidV0::from_parts_u64(1, [0; 32]).unwrap();
+        let expected = format!("{} (1 byte)", id);
+        assert_eq!(id.display_with_size().to_string(), expected);
+    }
+
+    #[test]
+    fn shard_key() {
+        let mut hash = [0u8; 32];
+        hash[..9].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let id = OcidV0::from_parts_u64(1, hash).unwrap();
+        assert_eq!(id.shard_key(), 0x0102_0304_0506_0708);
+
+        // The size does not affect the key.
+        let other = OcidV0::from_parts_u64(2, hash).unwrap();
+        assert_eq!(id.shard_key(), other.shard_key());
+    }
+
+    #[test]
+    fn base64_common_prefix_len() {
+        let mut rng = rand_core::OsRng;
+
+        let a = OcidV0::from_parts_u64(0, [0; 32]).unwrap();
+        let cases = [
+            (OcidV0::from_parts_u64(0, [0; 32]), BASE64_LEN),
+            (OcidV0::from_parts_u64(1 << 47, [0; 32]), 1),
+            (OcidV0::from_parts_u64(0, [0x10; 32]), 9),
+        ];
+
+        for &(b, len) in &cases {
+            let b = b.unwrap();
+            assert_eq!(a.base64_common_prefix_len(&b), len);
+            assert_eq!(b.base64_common_prefix_len(&a), len);
+        }
+
+        let mut hash = [0; 32];
+        hash[31] = 1;
+        let b = OcidV0::from_parts_u64(0, hash).unwrap();
+        assert_eq!(a.base64_common_prefix_len(&b), BASE64_LEN - 1);
+
+        for _ in 0..64 {
+            let a = OcidV0::rand(&mut rng);
+            let b = OcidV0::rand(&mut rng);
+
+            let (a_str, b_str) = (a.to_string(), b.to_string());
+            let expected = a_str
+                .bytes()
+                .zip(b_str.bytes())
+                .take_while(|(a, b)| a == b)
+                .count();
+            assert_eq!(a.base64_common_prefix_len(&b), expected);
+        }
+    }
+
+    #[test]
+    fn identity_u64() {
+        let mut hash = [0u8; 32];
+        hash[0] = 1;
+        hash[8] = 2;
+        hash[31] = 0x80;
+
+        let a = OcidV0::from_parts_u64(1, hash).unwrap();
+        assert_eq!(a.identity_u64(), 3 ^ (0x80 << 56));
+
+        // The size does not contribute.
+        let b = OcidV0::from_parts_u64(2, hash).unwrap();
+        assert_eq!(a.identity_u64(), b.identity_u64());
+
+        hash[20] = 1;
+        let c = OcidV0::from_parts_u64(1, hash).unwrap();
+        assert_ne!(a.identity_u64(), c.identity_u64());
+    }
+
+    #[test]
+    fn cmp_then_eq() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..64 {
+            let a = OcidV0::rand(&mut rng);
+            let b = OcidV0::rand(&mut rng);
+            let mut c = a;
+            c.set_hash(b.0.hash);
+
+            for (x, y) in &[(a, a), (a, b), (b, a), (a, c), (c, a)] {
+                assert_eq!(x.cmp_then_eq(y), (x.cmp(y), x == y));
+            }
+        }
+    }
+
+    #[test]
+    fn shortest_unique_prefix_len() {
+        let id = |last| {
+            let mut hash = [0xAA; 32];
+            hash[31] = last;
+            OcidV0::from_parts_u64(1, hash).unwrap()
+        };
+
+        // Only the final Base64 character differs.
+        let ids = [id(0), id(1), id(2)];
+        for target in &ids {
+            let len = OcidV0::shortest_unique_prefix_len(&ids, target);
+            assert_eq!(len, BASE64_LEN);
+        }
+
+        // Copies of the target are ignored.
+        let ids = [id(0), id(0x40), id(0x40), id(0x80)];
+        let len = OcidV0::shortest_unique_prefix_len(&ids, &id(0x40));
+        assert_eq!(len, BASE64_LEN - 1);
+
+        assert_eq!(OcidV0::shortest_unique_prefix_len(&[], &id(0)), 1);
+        assert_eq!(OcidV0::shortest_unique_prefix_len(&[id(0)], &id(0)), 1);
+
+        let mut rng = rand_core::OsRng;
+        let mut ids = [OcidV0::empty(); 64];
+        for id in ids.iter_mut() {
+            *id = OcidV0::rand(&mut rng);
+        }
+        ids.sort();
+
+        for target in &ids {
+            let brute = ids
+                .iter()
+                .filter(|id| *id != target)
+                .map(|id| target.base64_common_prefix_len(id) + 1)
+                .max()
+                .unwrap();
+            let len = OcidV0::shortest_unique_prefix_len(&ids, target);
+            assert_eq!(len, brute);
+        }
+    }
+
+    #[test]
+    fn hash_hamming_distance() {
+        let a = OcidV0::from_parts_u64(1, [0; 32]).unwrap();
+
+        let mut hash = [0u8; 32];
+        hash[0] = 0b1000_0001;
+        hash[15] = 0xFF;
+        hash[31] = 0b0001_0000;
+        let b = OcidV0::from_parts_u64(2, hash).unwrap();
+
+        assert_eq!(a.hash_hamming_distance(&a), 0);
+        assert_eq!(a.hash_hamming_distance(&b), 11);
+        assert_eq!(b.hash_hamming_distance(&a), 11);
+
+        let c = OcidV0::from_parts_u64(1, [0xFF; 32]).unwrap();
+        assert_eq!(a.hash_hamming_distance(&c), 256);
+    }
+
+    #[test]
+    fn same_hash() {
+        let a = OcidV0::from_parts_u64(1, [1; 32]).unwrap();
+        let cases = [
+            (OcidV0::from_parts_u64(1, [1; 32]), true, false),
+            (OcidV0::from_parts_u64(2, [1; 32]), true, true),
+            (OcidV0::from_parts_u64(1, [2; 32]), false, false),
+            (OcidV0::from_parts_u64(2, [2; 32]), false, false),
+        ];
+
+        for &(b, same_hash, conflicts) in &cases {
+            let b = b.unwrap();
+
+            assert_eq!(a.same_hash(&b), same_hash);
+            assert_eq!(b.same_hash(&a), same_hash);
+
+            assert_eq!(a.conflicts_with(&b), conflicts);
+            assert_eq!(b.conflicts_with(&a), conflicts);
+        }
+    }
+
+    #[test]
+    fn matches_or_unknown() {
+        let unknown = OcidV0::empty();
+        let a = OcidV0::from_parts_u64(1, [1; 32]).unwrap();
+        let b = OcidV0::from_parts_u64(1, [2; 32]).unwrap();
+
+        assert!(a.matches_or_unknown(&a));
+        assert!(!a.matches_or_unknown(&b));
+
+        let empty = OcidV0::EMPTY_CONTENT;
+        assert!(empty.is_empty());
+        assert!(empty.matches_or_unknown(&empty));
+        assert!(!empty.matches_or_unknown(&a));
+        assert!(!a.matches_or_unknown(&empty));
+
+        for id in &[unknown, empty, a, b] {
+            assert!(id.matches_or_unknown(&unknown));
+            assert!(unknown.matches_or_unknown(id));
+        }
+    }
+
+    #[test]
+    fn from_ref() {
+        let id = OcidV0::rand(rand_core::OsRng);
+
+        let bytes: [u8; LEN] = (&id).into();
+        assert_eq!(bytes, id.into_bytes());
+
+        let slice: &[u8] = (&id).into();
+        assert_eq!(slice, id.as_bytes());
+    }
+
+    #[test]
+    fn as_db_key() {
+        let mut rng = rand_core::OsRng;
+
+        let mut ids = [OcidV0::empty(); 64];
+        for id in ids.iter_mut() {
+            // Use few sizes so that ordering by hash is also exercised.
+            let size = rng.next_u64() % 4;
+            let mut hash = [0; 32];
+            rng.fill_bytes(&mut hash);
+            *id = OcidV0::from_parts_u64(size, hash).unwrap();
+        }
+
+        let mut by_ord = ids;
+        by_ord.sort();
+
+        let mut by_key = ids;
+        by_key.sort_by_key(|id| *id.as_db_key());
+
+        assert_eq!(by_ord, by_key);
+        assert!(by_key.windows(2).all(|w| w[0].size() <= w[1].size()));
+    }
+
+    #[test]
+    fn u64_array() {
+        let mut rng = rand_core::OsRng;
+
+        let a = OcidV0::from_parts_u64(0x0102_0304_0506, [0xAA; 32]).unwrap();
+        let array = a.to_u64_array();
+        assert_eq!(array[0], 0x0001_0203_0405_06AA);
+        assert_eq!(array[4], 0xAAAA_AAAA_AAAA_AA00);
+        assert_eq!(OcidV0::from_u64_array(array), Some(a));
+
+        // Padding is ignored.
+        let mut padded = array;
+        padded[4] |= 0xFF;
+        assert_eq!(OcidV0::from_u64_array(padded), Some(a));
+
+        // Bad version.
+        let mut bad = array;
+        bad[0] |= 1 << 56;
+        assert_eq!(OcidV0::from_u64_array(bad), None);
+
+        for _ in 0..64 {
+            let a = OcidV0::rand(&mut rng);
+            let b = OcidV0::rand(&mut rng);
+            assert_eq!(OcidV0::from_u64_array(a.to_u64_array()), Some(a));
+            assert_eq!(a.to_u64_array().cmp(&b.to_u64_array()), a.cmp(&b));
+        }
+    }
+
+    #[test]
+    fn u32_words() {
+        let mut rng = rand_core::OsRng;
+
+        let a = OcidV0::from_parts_u64(0x0102_0304_0506, [0xAA; 32]).unwrap();
+        let words = a.as_u32_words();
+        assert_eq!(words[0], 0x0001_0203);
+        assert_eq!(words[1], 0x0405_06AA);
+        assert_eq!(words[9], 0xAAAA_AA00);
+        assert_eq!(OcidV0::from_u32_words(words), Some(a));
+
+        // Padding is ignored.
+        let mut padded = words;
+        padded[9] |= 0xFF;
+        assert_eq!(OcidV0::from_u32_words(padded), Some(a));
+
+        // Bad version.
+        let mut bad = words;
+        bad[0] |= 1 << 24;
+        assert_eq!(OcidV0::from_u32_words(bad), None);
+
+        for _ in 0..64 {
+            let a = OcidV0::rand(&mut rng);
+            let b = OcidV0::rand(&mut rng);
+            assert_eq!(OcidV0::from_u32_words(a.as_u32_words()), Some(a));
+            assert_eq!(a.as_u32_words().cmp(&b.as_u32_words()), a.cmp(&b));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn try_from_vec() {
+        let id = OcidV0::rand(rand_core::OsRng);
+
+        let vec = id.as_bytes().to_vec();
+        assert_eq!(OcidV0::try_from(vec), Ok(id));
+
+        // Wrong length.
+        let mut vec = id.as_bytes().to_vec();
+        vec.push(0);
+        assert_eq!(OcidV0::try_from(vec.clone()), Err(vec));
+
+        let vec = id.as_bytes()[..LEN - 1].to_vec();
+        assert_eq!(OcidV0::try_from(vec.clone()), Err(vec));
+
+        // Bad version.
+        let mut vec = id.as_bytes().to_vec();
+        vec[0] = 1;
+        assert_eq!(OcidV0::try_from(vec.clone()), Err(vec));
+    }
+
+    #[test]
+    fn wire() {
+        let id = OcidV0::from_parts_u64(0x0102_0304_0506, [0xAA; 32]).unwrap();
+
+        let wire = id.to_wire();
+        assert_eq!(wire.len(), OcidV0::WIRE_LEN);
+        assert_eq!(wire[..7], [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(wire[7..], [0xAA; 32]);
+
+        assert_eq!(OcidV0::from_wire(&wire), Ok(id));
+
+        let mut wire = wire;
+        wire[0] = 2;
+        assert_eq!(
+            OcidV0::from_wire(&wire),
+            Err(ParseError::UnexpectedVersion(2)),
+        );
+        assert_eq!(
+            OcidV0::from_wire_slice(&wire),
+            Err(ParseError::UnexpectedVersion(2)),
+        );
+
+        wire[0] = 0;
+        assert_eq!(OcidV0::from_wire_slice(&wire), Ok(id));
+        assert_eq!(
+            OcidV0::from_wire_slice(&wire[1..]),
+            Err(ParseError::InvalidLength(LEN - 1)),
+        );
+        assert_eq!(
+            OcidV0::from_wire_slice(&[0; LEN + 1]),
+            Err(ParseError::InvalidLength(LEN + 1)),
+        );
+    }
+
+    #[test]
+    fn from_bytes_slice() {
+        let mut rng = rand_core::OsRng;
+
+        let mut ids = [OcidV0::empty(); 8];
+        for id in ids.iter_mut() {
+            *id = OcidV0::rand(&mut rng);
+        }
+
+        let mut bytes = [0u8; LEN * 8];
+        bytes.copy_from_slice(OcidV0::slice_as_bytes(&ids));
+
+        assert_eq!(OcidV0::from_bytes_slice(&bytes), Some(&ids[..]));
+        assert_eq!(OcidV0::from_bytes_slice(&[]), Some(&[][..]));
+
+        // Misaligned length.
+        assert_eq!(OcidV0::from_bytes_slice(&bytes[1..]), None);
+        assert_eq!(OcidV0::from_bytes_slice(&bytes[..LEN * 8 - 1]), None);
+
+        // Bad version.
+        bytes[LEN * 5] = 1;
+        assert_eq!(OcidV0::from_bytes_slice(&bytes), None);
+    }
+
+    #[test]
+    fn range_in_mmap() {
+        let id = |size| OcidV0::from_parts_u64(size, [0xAA; 32]).unwrap();
+
+        let ids = [id(1), id(3), id(3), id(5), id(7), id(9)];
+        let data = OcidV0::slice_as_bytes(&ids);
+
+        let range = |lo, hi| OcidV0::range_in_mmap(data, &id(lo), &id(hi));
+        assert_eq!(range(3, 7), Some(&ids[1..5]));
+        assert_eq!(range(2, 6), Some(&ids[1..4]));
+        assert_eq!(range(0, 100), Some(&ids[..]));
+        assert_eq!(range(4, 4), Some(&[][..]));
+        assert_eq!(range(10, 20), Some(&[][..]));
+        assert_eq!(range(7, 3), Some(&[][..]));
+
+        // Misaligned length.
+        let (lo, hi) = (id(0), id(100));
+        assert_eq!(OcidV0::range_in_mmap(&data[1..], &lo, &hi), None);
+        assert_eq!(OcidV0::range_in_mmap(&[], &lo, &hi), Some(&[][..]));
+
+        // Only probed and returned IDs have their versions checked.
+        let mut data = data.to_vec();
+        data[LEN * 5] = 1;
+        let range = |lo, hi| OcidV0::range_in_mmap(&data, &id(lo), &id(hi));
+        assert_eq!(range(1, 1), Some(&ids[..1]));
+        assert_eq!(range(7, 9), None);
+        assert_eq!(range(0, 100), None);
+    }
+
+    #[test]
+    fn patch_size_in_place() {
+        let hash = [0xAA; 32];
+        let ids = [
+            OcidV0::from_parts_u64(1, hash).unwrap(),
+            OcidV0::from_parts_u64(2, hash).unwrap(),
+        ];
+        let mut data = OcidV0::slice_as_bytes(&ids).to_vec();
+
+        OcidV0::patch_size_in_place(&mut data, 1, 5).unwrap();
+        let patched = OcidV0::from_bytes_slice(&data).unwrap();
+        assert_eq!(patched[0], ids[0]);
+        assert_eq!(patched[1], OcidV0::from_parts_u64(5, hash).unwrap());
+
+        let max = OcidV0::MAX_SIZE;
+        OcidV0::patch_size_in_place(&mut data, 0, max).unwrap();
+        assert_eq!(OcidV0::from_bytes_slice(&data).unwrap()[0].size(), max);
+
+        let before = data.clone();
+        assert_eq!(
+            OcidV0::patch_size_in_place(&mut data, 0, max + 1),
+            Err(OcidError::SizeTooLarge(max + 1)),
+        );
+        assert_eq!(
+            OcidV0::patch_size_in_place(&mut data, 2, 1),
+            Err(OcidError::IndexOutOfBounds { index: 2, len: 2 }),
+        );
+
+        // Misaligned or truncated data.
+        assert_eq!(
+            OcidV0::patch_size_in_place(&mut data[1..], 0, 1),
+            Err(OcidError::TrailingBytes(LEN - 1)),
+        );
+        assert_eq!(
+            OcidV0::patch_size_in_place(&mut data[..LEN + 1], 0, 1),
+            Err(OcidError::TrailingBytes(1)),
+        );
+
+        data[LEN] = 1;
+        assert_eq!(
+            OcidV0::patch_size_in_place(&mut data, 1, 1),
+            Err(OcidError::UnknownVersion(1)),
+        );
+        data[LEN] = 0;
+        assert_eq!(data, before);
+    }
+
+    #[test]
+    fn is_in_sorted_mmap() {
+        let id = |size| OcidV0::from_parts_u64(size, [0xAA; 32]).unwrap();
+
+        let ids = [id(1), id(3), id(3), id(5)];
+        let data = OcidV0::slice_as_bytes(&ids);
+
+        for size in 0..7 {
+            let expected = ids.contains(&id(size));
+            assert_eq!(
+                OcidV0::is_in_sorted_mmap(data, &id(size)),
+                Some(expected)
+            );
+        }
+
+        assert_eq!(OcidV0::is_in_sorted_mmap(&[], &id(1)), Some(false));
+        assert_eq!(OcidV0::is_in_sorted_mmap(&data[1..], &id(1)), None);
+
+        // Only probed IDs have their versions checked.
+        let mut data = data.to_vec();
+        data[LEN * 3] = 1;
+        assert_eq!(OcidV0::is_in_sorted_mmap(&data, &id(1)), Some(true));
+        assert_eq!(OcidV0::is_in_sorted_mmap(&data, &id(5)), None);
+    }
+
+    #[test]
+    fn chunks_of() {
+        let ids: Vec<OcidV0> = (0..10)
+            .map(|size| OcidV0::from_parts_u64(size, [0; 32]).unwrap())
+            .collect();
+
+        let lens = |n| {
+            OcidV0::chunks_of(&ids, n)
+                .map(|chunk| chunk.len())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(lens(1), [10]);
+        assert_eq!(lens(3), [4, 3, 3]);
+        assert_eq!(lens(5), [2, 2, 2, 2, 2]);
+        assert_eq!(lens(20), [1; 10]);
+        assert_eq!(OcidV0::chunks_of(&[], 4).count(), 0);
+
+        let joined: Vec<OcidV0> =
+            OcidV0::chunks_of(&ids, 4).flatten().copied().collect();
+        assert_eq!(joined, ids);
+    }
+
+    #[test]
+    fn slices_set_equal() {
+        let id = |size| OcidV0::from_parts_u64(size, [0xAA; 32]).unwrap();
+        let (a, b, c, d) = (id(1), id(2), id(3), id(4));
+
+        let cases: &[(&[OcidV0], &[OcidV0], bool)] = &[
+            // Equal.
+            (&[], &[], true),
+            (&[a, b, c], &[a, b, c], true),
+            (&[a, b, b, c], &[a, a, b, c, c], true),
+            (&[c, a, b], &[b, c, a], true),
+            (&[c, a, b, a], &[a, b, c], true),
+            // Subset.
+            (&[a, b], &[a, b, c], false),
+            (&[a, b, c], &[a, c], false),
+            (&[b, a], &[c, a, b], false),
+            (&[], &[a], false),
+            // Disjoint.
+            (&[a, b], &[c, d], false),
+            (&[b, a], &[d, c], false),
+        ];
+
+        for &(x, y, equal) in cases {
+            assert_eq!(OcidV0::slices_set_equal(x, y), equal, "{:?}", (x, y));
+            assert_eq!(OcidV0::slices_set_equal(y, x), equal, "{:?}", (y, x));
+        }
+    }
+
+    #[test]
+    fn count_in() {
+        let bytes = [0u8; LEN * 3 + 1];
+
+        assert_eq!(OcidV0::count_in(&bytes), 3);
+        assert_eq!(OcidV0::count_in(&bytes[..LEN * 3]), 3);
+        assert_eq!(OcidV0::count_in(&bytes[..LEN - 1]), 0);
+
+        assert!(!OcidV0::fits_exactly(&bytes));
+        assert!(OcidV0::fits_exactly(&bytes[..LEN * 3]));
+        assert!(OcidV0::fits_exactly(&[]));
+    }
+
+    #[test]
+    fn validate_slice() {
+        let mut bytes = [0u8; LEN * 4];
+
+        assert_eq!(OcidV0::validate_slice(&bytes), Ok(4));
+        assert_eq!(OcidV0::validate_slice(&[]), Ok(0));
+
+        // Partial record.
+        assert_eq!(OcidV0::validate_slice(&bytes[..LEN * 3 + 5]), Err(LEN * 3));
+        assert_eq!(OcidV0::validate_slice(&bytes[..5]), Err(0));
+
+        // Bad version, which is reported before a later partial record.
+        bytes[LEN * 2] = 1;
+        assert_eq!(OcidV0::validate_slice(&bytes), Err(LEN * 2));
+        assert_eq!(OcidV0::validate_slice(&bytes[..LEN * 4 - 1]), Err(LEN * 2));
+    }
+
+    #[test]
+    fn iter_from_slice() {
+        let mut rng = rand_core::OsRng;
+
+        let mut ids = [OcidV0::empty(); 4];
+        for id in ids.iter_mut() {
+            *id = OcidV0::rand(&mut rng);
+        }
+
+        let mut bytes = [0u8; LEN * 4];
+        bytes.copy_from_slice(OcidV0::slice_as_bytes(&ids));
+        bytes[LEN] = 1;
+
+        // Deliberately truncate the final record.
+        let bytes = &bytes[..LEN * 4 - 10];
+
+        let mut iter = OcidV0::iter_from_slice(bytes);
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(Ok(&ids[0])));
+        assert_eq!(iter.next(), Some(Err(ParseError::UnexpectedVersion(1))));
+        assert_eq!(iter.next(), Some(Ok(&ids[2])));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(Err(ParseError::Truncated(LEN - 10))));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn parse_error_display() {
+        assert_eq!(
+            ParseError::Truncated(7).to_string(),
+            "expected 39 bytes for OCID version 0 but input ended after 7",
+        );
+        assert_eq!(
+            ParseError::UnexpectedVersion(3).to_string(),
+            "expected OCID version 0 but found version 3",
+        );
+
+        // The underlying error is displayed, so it's not also the source.
+        let error = ParseError::Base64(base64::DecodeError::InvalidLength(5));
+        assert_eq!(
+            error.to_string(),
+            "expected 52 Base64 characters but found 5",
+        );
+        #[cfg(feature = "std")]
+        assert!(std::error::Error::source(&error).is_none());
+    }
+
+    #[test]
+    fn from_str() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..64 {
+            let id = OcidV0::rand(&mut rng);
+            assert_eq!(id.to_string().parse(), Ok(id));
+        }
+
+        let mut bytes = [0u8; LEN];
+        bytes[0] = 1;
+        let raw = RawOcidV0::from_bytes(bytes);
+        assert_eq!(
+            raw.with_base64(|b64| b64.parse::<OcidV0>()),
+            Err(ParseError::UnexpectedVersion(1)),
+        );
+    }
+
+    #[test]
+    fn decode_base64_into() {
+        let mut rng = rand_core::OsRng;
+        let mut ids = [OcidV0::empty(); 16];
+
+        let b64s: Vec<String> = (0..ids.len())
+            .map(|_| OcidV0::rand(&mut rng).to_string())
+            .collect();
+
+        for (b64, id) in b64s.iter().zip(ids.iter_mut()) {
+            OcidV0::decode_base64_into(b64, id).unwrap();
+            assert_eq!(&id.to_string(), b64);
+        }
+
+        let mut bytes = [0u8; LEN];
+        bytes[0] = 1;
+        let b64 =
+            RawOcidV0::from_bytes(bytes).with_base64(|b64| b64.to_owned());
+
+        let old = ids[0];
+        assert_eq!(
+            OcidV0::decode_base64_into(&b64, &mut ids[0]),
+            Err(base64::DecodeError::UnexpectedVersion(1)),
+        );
+        assert_eq!(ids[0], old);
+    }
+
+    #[test]
+    fn decode_base64_bytes() {
+        let id = OcidV0::rand(rand_core::OsRng);
+        let mut buf = [0u8; BASE64_LEN];
+        id.encode_base64(&mut buf);
+
+        assert_eq!(OcidV0::decode_base64_bytes(&buf), Ok(id));
+
+        // Not UTF-8.
+        buf[10] = 0xFF;
+        assert!(core::str::from_utf8(&buf).is_err());
+        assert_eq!(
+            OcidV0::decode_base64_bytes(&buf),
+            Err(base64::DecodeError::InvalidChar {
+                index: 10,
+                byte: 0xFF,
+            }),
+        );
+
+        assert_eq!(
+            OcidV0::decode_base64_bytes(&buf[..BASE64_LEN - 1]),
+            Err(base64::DecodeError::InvalidLength(BASE64_LEN - 1)),
+        );
+    }
+
+    #[test]
+    fn decode_unexpected_version() {
+        let mut bytes = [0u8; LEN];
+        bytes[0] = 1;
+        let v1 = RawOcidV0::from_bytes(bytes).with_base64(|b64| b64.to_owned());
+
+        let error = base64::DecodeError::UnexpectedVersion(1);
+        let mut id = OcidV0::empty();
+
+        assert_eq!(OcidV0::decode_base64_into(&v1, &mut id), Err(error));
+        assert_eq!(v1.parse::<OcidV0>(), Err(ParseError::UnexpectedVersion(1)));
+
+        // A different version may be longer.
+        let v1_long = format!("{}----", v1);
+        assert_eq!(OcidV0::decode_base64_into(&v1_long, &mut id), Err(error));
+
+        // Garbage is still reported as such.
+        for garbage in &["bad", "hello", "some-package-name", &v1[..10]] {
+            assert_eq!(
+                OcidV0::decode_base64_into(garbage, &mut id),
+                Err(base64::DecodeError::InvalidLength(garbage.len())),
+            );
+        }
+        assert_eq!(
+            OcidV0::decode_base64_into("=", &mut id),
+            Err(base64::DecodeError::InvalidLength(1)),
+        );
+        assert_eq!(
+            OcidV0::decode_base64_into(&format!("{}---!", v1), &mut id),
+            Err(base64::DecodeError::InvalidChar {
+                index: BASE64_LEN + 3,
+                byte: b'!',
+            }),
+        );
+    }
+
+    #[test]
+    fn parse_lines() {
+        let mut rng = rand_core::OsRng;
+        let a = OcidV0::rand(&mut rng);
+        let b = OcidV0::rand(&mut rng);
+
+        let text =
+            format!("# Lockfile\n\n  {}  \r\n\t# {}\n{}\n--bad\n", a, a, b,);
+
+        let mut lines = OcidV0::parse_lines(&text);
+        assert_eq!(lines.next(), Some(Ok(a)));
+        assert_eq!(lines.next(), Some(Ok(b)));
+        let error = ParseError::Base64(base64::DecodeError::InvalidLength(5));
+        assert_eq!(lines.next(), Some(Err(LineError { line: 6, error })));
+        assert_eq!(lines.next(), None);
+
+        assert_eq!(OcidV0::parse_lines("").next(), None);
+        assert_eq!(OcidV0::parse_lines("\n# only\n").next(), None);
+    }
+
+    #[test]
+    fn parse_lenient() {
+        let id = OcidV0::rand(rand_core::OsRng);
+        let b64 = id.to_string();
+
+        for s in &[
+            b64.clone(),
+            format!("  {}\n", b64),
+            format!("\t{}\r\n", b64),
+            format!("ocid:{}", b64),
+            format!("ocid:0:{}", b64),
+            format!(" ocid:0:{} \n", b64),
+        ] {
+            assert_eq!(OcidV0::parse_lenient(s), Ok(id), "{:?}", s);
+        }
+
+        let (head, tail) = b64.split_at(20);
+        for s in &[
+            format!("{} {}", head, tail),
+            format!("{}\n{}", head, tail),
+            format!("ocid: {}", b64),
+        ] {
+            assert!(OcidV0::parse_lenient(s).is_err(), "{:?}", s);
+            assert!(s.parse::<OcidV0>().is_err(), "{:?}", s);
+        }
+
+        assert!(format!(" {}", b64).parse::<OcidV0>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn checked() {
+        let id = OcidV0::rand(rand_core::OsRng);
+        let checked = id.to_string_checked();
+
+        assert_eq!(checked.len(), BASE64_LEN + 1);
+        assert!(checked.starts_with(&id.to_string()));
+        assert_eq!(OcidV0::parse_checked(&checked), Ok(id));
+
+        assert!(checked.parse::<OcidV0>().is_err());
+        assert!(OcidV0::parse_checked(&id.to_string()).is_err());
+        assert!(OcidV0::parse_checked("").is_err());
+
+        // Every single-character typo is caught.
+        for i in 0..BASE64_LEN {
+            for &c in base64::ALPHABET.iter() {
+                let mut typo = checked.clone().into_bytes();
+                if typo[i] == c {
+                    continue;
+                }
+                typo[i] = c;
+
+                let typo = String::from_utf8(typo).unwrap();
+                assert!(OcidV0::parse_checked(&typo).is_err(), "{}", typo);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn to_path_buf() {
+        use std::path::Path;
+
+        let id = OcidV0::rand(rand_core::OsRng);
+        let b64 = id.to_string();
+
+        let path = id.to_path_buf(2, 2);
+        let expected = Path::new(&b64[..2]).join(&b64[2..4]).join(&b64[4..]);
+        assert_eq!(path, expected);
+
+        assert_eq!(id.to_path_buf(0, 2), Path::new(&b64));
+        assert_eq!(id.to_path_buf(3, 0), Path::new(&b64));
+
+        // Only as many levels as fit are used.
+        let path = id.to_path_buf(100, 10);
+        assert_eq!(path.components().count(), 6);
+        assert_eq!(path.file_name().unwrap(), &b64[50..]);
+
+        let path = id.to_path_buf(1, 100);
+        assert_eq!(path, Path::new(&b64));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_base64_cow() {
+        let id = OcidV0::rand(rand_core::OsRng);
+
+        let cow = id.to_base64_cow();
+        assert!(matches!(cow, alloc::borrow::Cow::Owned(_)));
+        assert_eq!(cow, id.to_string());
+        assert_eq!(cow.len(), OcidV0::BASE64_LEN);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn looks_like() {
+        let id = OcidV0::rand(rand_core::OsRng).to_string();
+        assert!(OcidV0::looks_like(&id));
+
+        // Wrong lengths.
+        assert!(!OcidV0::looks_like(""));
+        assert!(!OcidV0::looks_like(&id[1..]));
+        assert!(!OcidV0::looks_like(&format!("{}-", id)));
+        assert!(!OcidV0::looks_like(&format!(" {}", id)));
+
+        // Characters outside the alphabet.
+        for bad in &["+", "/", "=", ".", " ", "é"] {
+            let s = format!("{}{}", bad, &id[bad.len()..]);
+            assert_eq!(s.len(), BASE64_LEN);
+            assert!(!OcidV0::looks_like(&s), "{:?}", s);
+        }
+
+        // The version is not checked.
+        let version_1 = format!("0{}", &id[1..]);
+        assert!(OcidV0::looks_like(&version_1));
+        assert!(version_1.parse::<OcidV0>().is_err());
+
+        assert!(OcidV0::looks_like(&"z".repeat(BASE64_LEN)));
+        assert!(!OcidV0::looks_like("some-package-name"));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn hex_string() {
+        let id = OcidV0::from_parts_u64(0x0102, [0xAB; 32]).unwrap();
+
+        let hex = id.to_hex_string();
+        assert_eq!(hex.len(), 78);
+        assert!(hex.starts_with("00000000000102abab"));
+        assert_eq!(OcidV0::from_hex_str(&hex), Ok(id));
+        assert_eq!(OcidV0::from_hex_str(&hex.to_uppercase()), Ok(id));
+
+        for _ in 0..64 {
+            let id = OcidV0::rand(rand_core::OsRng);
+            assert_eq!(OcidV0::from_hex_str(&id.to_hex_string()), Ok(id));
+        }
+
+        assert_eq!(
+            OcidV0::from_hex_str(&hex[2..]),
+            Err(ParseError::Hex(hex::DecodeError::InvalidLength {
+                expected: 78,
+                found: 76,
+            })),
+        );
+
+        let bad_char = format!("{}x", &hex[..77]);
+        assert_eq!(
+            OcidV0::from_hex_str(&bad_char),
+            Err(ParseError::Hex(hex::DecodeError::InvalidChar {
+                index: 77,
+                byte: b'x',
+            })),
+        );
+
+        let bad_version = format!("01{}", &hex[2..]);
+        assert_eq!(
+            OcidV0::from_hex_str(&bad_version),
+            Err(ParseError::UnexpectedVersion(1)),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_url_segment() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..64 {
+            let id = OcidV0::rand(&mut rng);
+            let segment = id.to_url_segment();
+
+            assert_eq!(segment.len(), 54);
+            assert!(segment.starts_with("v0-"));
+            assert!(segment
+                .bytes()
+                .all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_'));
+            assert_eq!(segment[2..].parse::<OcidV0>(), Ok(id));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_data_fragment() {
+        let id = OcidV0::rand(rand_core::OsRng);
+
+        let fragment = id.to_data_fragment();
+        assert_eq!(fragment, format!("#ocid={}", id));
+        assert_eq!(fragment.len(), 6 + OcidV0::BASE64_LEN);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_base64_string() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..64 {
+            let id = OcidV0::rand(&mut rng);
+            assert_eq!(id.to_base64_string(), id.to_string());
+        }
+    }
 }
@@ -0,0 +1,78 @@
+use multihash::Multihash;
+
+use super::OcidV0;
+
+/// The [multicodec] code for a 32-byte [BLAKE3] hash.
+///
+/// [multicodec]: https://github.com/multiformats/multicodec
+/// [BLAKE3]:     https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+const BLAKE3_CODE: u64 = 0x1e;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "multihash")))]
+impl OcidV0 {
+    /// Returns the [BLAKE3] hash of the content as a [multihash].
+    ///
+    /// The size of the content has no multihash representation and is thus
+    /// dropped. Use [`from_multihash`](#method.from_multihash) to reattach it.
+    ///
+    /// [BLAKE3]:    https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    /// [multihash]: https://multiformats.io/multihash
+    #[inline]
+    pub fn to_multihash(&self) -> Multihash<32> {
+        match Multihash::wrap(BLAKE3_CODE, self.hash()) {
+            Ok(mh) => mh,
+            Err(_) => unreachable!("BLAKE3 hash does not fit in 32 bytes"),
+        }
+    }
+
+    /// Creates an ID from a [BLAKE3] [multihash] and the size of its content.
+    ///
+    /// Returns `None` if `mh` is not a 32-byte BLAKE3 hash or if `size` is
+    /// larger than [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    ///
+    /// [BLAKE3]:    https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    /// [multihash]: https://multiformats.io/multihash
+    #[inline]
+    pub fn from_multihash<const S: usize>(
+        mh: &Multihash<S>,
+        size: u64,
+    ) -> Option<OcidV0> {
+        if mh.code() != BLAKE3_CODE {
+            return None;
+        }
+
+        let mut hash = [0u8; 32];
+        if mh.digest().len() != hash.len() {
+            return None;
+        }
+        hash.copy_from_slice(mh.digest());
+
+        Self::from_parts_u64(size, hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let id = OcidV0::new(b"hello, world").unwrap();
+
+        let mh = id.to_multihash();
+        assert_eq!(mh.code(), BLAKE3_CODE);
+        assert_eq!(mh.digest(), id.hash());
+
+        assert_eq!(OcidV0::from_multihash(&mh, id.size()), Some(id));
+        assert_eq!(
+            OcidV0::from_multihash(&mh.resize::<64>().unwrap(), 12),
+            Some(id)
+        );
+    }
+
+    #[test]
+    fn wrong_code() {
+        let mh = Multihash::<32>::wrap(0x12, &[0; 32]).unwrap();
+        assert_eq!(OcidV0::from_multihash(&mh, 0), None);
+    }
+}
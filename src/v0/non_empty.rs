@@ -0,0 +1,198 @@
+use core::{convert::TryFrom, fmt, ops::Deref, str};
+
+use super::{OcidV0, ParseError};
+
+/// An [`OcidV0`](struct.OcidV0.html) whose content size is known to not be 0.
+///
+/// Ocean does not accept IDs with a size of 0, so this type can be used as a
+/// field type to enforce that invariant when deserializing, rather than
+/// checking [`is_empty`](struct.OcidV0.html#method.is_empty) at every call
+/// site.
+///
+/// With the `serde` feature, this is (de)serialized as its [Base64] string,
+/// and deserializing an ID with a size of 0 fails.
+///
+/// [Base64]: https://en.wikipedia.org/wiki/Base64
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyOcidV0(OcidV0);
+
+impl Deref for NonEmptyOcidV0 {
+    type Target = OcidV0;
+
+    #[inline]
+    fn deref(&self) -> &OcidV0 {
+        &self.0
+    }
+}
+
+impl AsRef<OcidV0> for NonEmptyOcidV0 {
+    #[inline]
+    fn as_ref(&self) -> &OcidV0 {
+        &self.0
+    }
+}
+
+impl From<NonEmptyOcidV0> for OcidV0 {
+    #[inline]
+    fn from(id: NonEmptyOcidV0) -> Self {
+        id.0
+    }
+}
+
+impl TryFrom<OcidV0> for NonEmptyOcidV0 {
+    type Error = OcidV0;
+
+    /// Returns `id` back if it has a size of 0.
+    #[inline]
+    fn try_from(id: OcidV0) -> Result<Self, OcidV0> {
+        Self::new(id).ok_or(id)
+    }
+}
+
+impl fmt::Display for NonEmptyOcidV0 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl str::FromStr for NonEmptyOcidV0 {
+    type Err = ParseError;
+
+    /// Parses an ID like [`OcidV0`](struct.OcidV0.html) does, but returns
+    /// [`ParseError::Empty`](enum.ParseError.html#variant.Empty) if it has a
+    /// size of 0.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.parse()?).ok_or(ParseError::Empty)
+    }
+}
+
+/// Serializes the ID like [`OcidV0`](struct.OcidV0.html), as its
+/// [Base64](https://en.wikipedia.org/wiki/Base64) string.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for NonEmptyOcidV0 {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Deserializes the ID like [`OcidV0`](struct.OcidV0.html), but fails if it
+/// has a size of 0.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for NonEmptyOcidV0 {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let id = OcidV0::deserialize(deserializer)?;
+        Self::new(id).ok_or_else(|| serde::de::Error::custom(ParseError::Empty))
+    }
+}
+
+impl NonEmptyOcidV0 {
+    /// Wraps `id` if it does not have a size of 0.
+    #[inline]
+    pub fn new(id: OcidV0) -> Option<Self> {
+        if id.is_empty() {
+            None
+        } else {
+            Some(Self(id))
+        }
+    }
+
+    /// Returns the wrapped ID.
+    #[inline]
+    pub fn get(self) -> OcidV0 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let id = OcidV0::new(b"hello").unwrap();
+        let non_empty = NonEmptyOcidV0::new(id).unwrap();
+        assert_eq!(non_empty.get(), id);
+        assert_eq!(non_empty.size(), 5);
+        assert_eq!(NonEmptyOcidV0::try_from(id), Ok(non_empty));
+
+        let empty = OcidV0::new(&[]).unwrap();
+        assert_eq!(NonEmptyOcidV0::new(empty), None);
+        assert_eq!(NonEmptyOcidV0::try_from(empty), Err(empty));
+    }
+
+    #[test]
+    fn rejects_empty() {
+        // The size alone determines emptiness, regardless of the hash.
+        let ids = [
+            OcidV0::new(&[]).unwrap(),
+            OcidV0::from_parts([0; 6], [0xFF; 32]),
+        ];
+
+        for &id in &ids {
+            assert!(id.is_empty());
+            assert_eq!(NonEmptyOcidV0::new(id), None);
+            assert_eq!(NonEmptyOcidV0::try_from(id), Err(id));
+            assert_eq!(
+                id.to_string().parse::<NonEmptyOcidV0>(),
+                Err(ParseError::Empty),
+            );
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        let id = OcidV0::new(b"hello").unwrap();
+        let parsed = id.to_string().parse::<NonEmptyOcidV0>().unwrap();
+        assert_eq!(parsed.get(), id);
+
+        let empty = OcidV0::new(&[]).unwrap().to_string();
+        assert_eq!(empty.parse::<NonEmptyOcidV0>(), Err(ParseError::Empty));
+        assert!(matches!(
+            "".parse::<NonEmptyOcidV0>(),
+            Err(ParseError::Base64(_)),
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Package {
+            id: NonEmptyOcidV0,
+        }
+
+        let id = OcidV0::new(b"hello").unwrap();
+        let package = Package {
+            id: NonEmptyOcidV0::new(id).unwrap(),
+        };
+
+        let json = serde_json::to_string(&package).unwrap();
+        assert_eq!(json, format!(r#"{{"id":"{}"}}"#, id));
+        assert_eq!(serde_json::from_str::<Package>(&json).unwrap(), package);
+
+        let empty = OcidV0::new(&[]).unwrap();
+        let json = format!(r#"{{"id":"{}"}}"#, empty);
+        let error = serde_json::from_str::<Package>(&json).unwrap_err();
+        assert!(
+            error.to_string().contains(&ParseError::Empty.to_string()),
+            "{}",
+            error,
+        );
+
+        assert!(serde_json::from_str::<Package>(r#"{"id":"bad"}"#).is_err());
+        assert!(serde_json::from_str::<Package>(r#"{"id":5}"#).is_err());
+    }
+}
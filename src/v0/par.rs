@@ -0,0 +1,65 @@
+use core::convert::TryFrom;
+
+use rayon::prelude::*;
+
+use super::{OcidV0, BASE64_LEN};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl OcidV0 {
+    /// Writes the [Base64] encodings of `ids` to `buf` in parallel using
+    /// [`rayon`].
+    ///
+    /// The encoding of `ids[i]` is written to
+    /// `buf[i * BASE64_LEN..(i + 1) * BASE64_LEN]`, without any separators.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `buf` is not
+    /// [`BASE64_LEN`](#associatedconstant.BASE64_LEN) times the length of
+    /// `ids`.
+    ///
+    /// [`rayon`]: https://docs.rs/rayon
+    ///
+    /// [Base64]: https://en.wikipedia.org/wiki/Base64
+    pub fn par_encode(ids: &[OcidV0], buf: &mut [u8]) {
+        assert_eq!(
+            buf.len(),
+            ids.len() * BASE64_LEN,
+            "buffer length must be {} times the number of IDs",
+            BASE64_LEN,
+        );
+
+        buf.par_chunks_exact_mut(BASE64_LEN)
+            .zip(ids.par_iter())
+            .for_each(|(chunk, id)| {
+                let chunk = <&mut [u8; BASE64_LEN]>::try_from(chunk).unwrap();
+                id.encode_base64(chunk);
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn par_encode() {
+        let mut rng = rand_core::OsRng;
+        let ids: Vec<OcidV0> =
+            (0..100).map(|_| OcidV0::rand(&mut rng)).collect();
+
+        let mut buf = vec![0u8; ids.len() * BASE64_LEN];
+        OcidV0::par_encode(&ids, &mut buf);
+
+        for (id, b64) in ids.iter().zip(buf.chunks_exact(BASE64_LEN)) {
+            assert_eq!(b64, id.to_string().as_bytes());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn par_encode_wrong_len() {
+        let ids = [OcidV0::empty(); 2];
+        OcidV0::par_encode(&ids, &mut [0; BASE64_LEN]);
+    }
+}
@@ -0,0 +1,93 @@
+use postcard::{ser_flavors::Flavor, Error};
+use serde::Serialize;
+
+use super::{OcidV0, OcidV0Hasher};
+
+/// A postcard flavor that hashes serialized bytes instead of storing them.
+struct HashFlavor(OcidV0Hasher);
+
+impl Flavor for HashFlavor {
+    type Output = OcidV0Hasher;
+
+    #[inline]
+    fn try_push(&mut self, data: u8) -> postcard::Result<()> {
+        self.0.update_unbounded(&[data]);
+        Ok(())
+    }
+
+    #[inline]
+    fn try_extend(&mut self, data: &[u8]) -> postcard::Result<()> {
+        self.0.update_unbounded(data);
+        Ok(())
+    }
+
+    #[inline]
+    fn finalize(self) -> postcard::Result<OcidV0Hasher> {
+        Ok(self.0)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "postcard")))]
+impl OcidV0 {
+    /// Generates an ID by hashing the [postcard] serialization of `value`
+    /// using [BLAKE3], such as for a cache key of a configuration struct.
+    ///
+    /// The serialized bytes are hashed as they are produced, so this does not
+    /// allocate. The ID is the same as calling [`new`](#method.new) on the
+    /// output of `postcard::to_allocvec(value)`.
+    ///
+    /// # Stability
+    ///
+    /// The ID depends on the serialization format, and thus is only stable
+    /// for as long as postcard's wire format and the `Serialize`
+    /// implementation of `T` stay the same. Changing a field name does not
+    /// change the ID, but changing a field type, order, or count does.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from serializing `value`, or
+    /// [`SerializeBufferFull`] if the serialization is larger than
+    /// [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    ///
+    /// [`SerializeBufferFull`]: https://docs.rs/postcard/1/postcard/enum.Error.html#variant.SerializeBufferFull
+    ///
+    /// [postcard]: https://docs.rs/postcard/1
+    /// [BLAKE3]:   https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    pub fn of_serializable<T>(value: &T) -> Result<OcidV0, Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let flavor = HashFlavor(OcidV0Hasher::new());
+        let hasher = postcard::serialize_with_flavor(value, flavor)?;
+        hasher.finalize().ok_or(Error::SerializeBufferFull)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Config<'a> {
+        name: &'a str,
+        jobs: u32,
+        features: &'a [&'a str],
+    }
+
+    #[test]
+    fn of_serializable() {
+        let config = Config {
+            name: "ocean",
+            jobs: 4,
+            features: &["std", "blake3"],
+        };
+        let id = OcidV0::of_serializable(&config).unwrap();
+
+        let mut buf = [0u8; 64];
+        let bytes = postcard::to_slice(&config, &mut buf).unwrap();
+        assert_eq!(Some(id), OcidV0::new(bytes));
+
+        let other = Config { jobs: 8, ..config };
+        assert_ne!(OcidV0::of_serializable(&other).unwrap(), id);
+    }
+}
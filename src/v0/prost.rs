@@ -0,0 +1,57 @@
+use prost::bytes::Bytes;
+
+use super::{OcidV0, ParseError};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "prost")))]
+impl OcidV0 {
+    /// Returns the 39-byte [wire format](#method.to_wire) of the ID as
+    /// [`Bytes`], for a protobuf `bytes` field.
+    ///
+    /// [`Bytes`]: https://docs.rs/bytes/1/bytes/struct.Bytes.html
+    #[inline]
+    pub fn to_prost_bytes(&self) -> Bytes {
+        Bytes::copy_from_slice(self.as_bytes())
+    }
+
+    /// Creates an ID from the contents of a protobuf `bytes` field, such as
+    /// from [`to_prost_bytes`](#method.to_prost_bytes).
+    ///
+    /// Returns an error if `bytes` is not 39 bytes long or if the version is
+    /// not 0.
+    #[inline]
+    pub fn from_prost_bytes(bytes: &[u8]) -> Result<OcidV0, ParseError> {
+        Self::from_wire_slice(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v0::LEN;
+
+    #[test]
+    fn round_trip() {
+        let id = OcidV0::rand(rand_core::OsRng);
+
+        let bytes = id.to_prost_bytes();
+        assert_eq!(bytes.len(), LEN);
+        assert_eq!(OcidV0::from_prost_bytes(&bytes), Ok(id));
+
+        assert_eq!(
+            OcidV0::from_prost_bytes(&bytes[1..]),
+            Err(ParseError::InvalidLength(LEN - 1)),
+        );
+        assert_eq!(
+            OcidV0::from_prost_bytes(&[]),
+            Err(ParseError::InvalidLength(0)),
+        );
+
+        let mut wire = id.to_wire();
+        wire[0] = 2;
+        let bytes = Bytes::copy_from_slice(&wire);
+        assert_eq!(
+            OcidV0::from_prost_bytes(&bytes),
+            Err(ParseError::UnexpectedVersion(2)),
+        );
+    }
+}
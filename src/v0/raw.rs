@@ -7,7 +7,14 @@ use super::{BASE64_LEN, LEN};
 use crate::enc::base64;
 
 /// The raw parts of an [`OcidV0`](struct.OcidV0.html).
+///
+/// With the `bytemuck` feature, this implements [`Pod`] and [`Zeroable`],
+/// which allows for safely casting between `&[RawOcidV0]` and `&[u8]`.
+///
+/// [`Pod`]:      https://docs.rs/bytemuck/1/bytemuck/trait.Pod.html
+/// [`Zeroable`]: https://docs.rs/bytemuck/1/bytemuck/trait.Zeroable.html
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[repr(C)]
 pub struct RawOcidV0 {
     /// The ID version.
@@ -36,6 +43,14 @@ impl RawOcidV0 {
         unsafe { mem::transmute(bytes) }
     }
 
+    /// Returns the ID version.
+    ///
+    /// This can be used in const contexts.
+    #[inline]
+    pub const fn version_const(&self) -> u8 {
+        self.version
+    }
+
     /// Returns a slice of bytes for all of `ids`.
     #[inline]
     pub fn slice_as_bytes(ids: &[Self]) -> &[u8] {
@@ -99,3 +114,25 @@ impl RawOcidV0 {
         base64::encode_base8_39_uninit(self.as_bytes(), buf)
     }
 }
+
+#[cfg(all(test, feature = "bytemuck"))]
+mod tests {
+    use super::*;
+    use rand_core::RngCore;
+
+    #[test]
+    fn bytemuck_cast_slice() {
+        let mut rng = rand_core::OsRng;
+
+        let mut ids = [RawOcidV0::default(); 8];
+        for id in ids.iter_mut() {
+            rng.fill_bytes(&mut id.hash);
+        }
+
+        let bytes: &[u8] = bytemuck::cast_slice(&ids);
+        assert_eq!(bytes, RawOcidV0::slice_as_bytes(&ids));
+
+        let cast: &[RawOcidV0] = bytemuck::cast_slice(bytes);
+        assert_eq!(cast, &ids[..]);
+    }
+}
@@ -0,0 +1,79 @@
+use redis::{
+    ErrorKind, FromRedisValue, RedisError, RedisResult, RedisWrite,
+    ToRedisArgs, Value,
+};
+
+use super::OcidV0;
+
+/// Writes the 39-byte [wire format](struct.OcidV0.html#method.to_wire) of the
+/// ID as a single argument.
+#[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
+impl ToRedisArgs for OcidV0 {
+    #[inline]
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.as_bytes());
+    }
+}
+
+/// Fails with a [`TypeError`] if the value is not 39 bytes starting with
+/// version 0.
+///
+/// [`TypeError`]: https://docs.rs/redis/0.27/redis/enum.ErrorKind.html#variant.TypeError
+#[cfg_attr(docsrs, doc(cfg(feature = "redis")))]
+impl FromRedisValue for OcidV0 {
+    fn from_redis_value(value: &Value) -> RedisResult<Self> {
+        let bytes = std::vec::Vec::<u8>::from_redis_value(value)?;
+        OcidV0::from_wire_slice(&bytes).map_err(|error| {
+            RedisError::from((
+                ErrorKind::TypeError,
+                "invalid OCID version 0",
+                std::string::ToString::to_string(&error),
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let id = OcidV0::new(b"hello, world").unwrap();
+
+        let args = id.to_redis_args();
+        assert_eq!(args, [id.as_bytes().to_vec()]);
+
+        let value = Value::BulkString(args[0].clone());
+        assert_eq!(OcidV0::from_redis_value(&value).unwrap(), id);
+    }
+
+    #[test]
+    fn malformed() {
+        let id = OcidV0::new(b"hello, world").unwrap();
+        let mut wire = id.to_wire();
+
+        let value = Value::BulkString(wire[1..].to_vec());
+        let error = OcidV0::from_redis_value(&value).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::TypeError);
+        assert_eq!(
+            error.detail(),
+            Some("expected 39 bytes for OCID version 0 but found 38"),
+        );
+
+        wire[0] = 1;
+        let value = Value::BulkString(wire.to_vec());
+        let error = OcidV0::from_redis_value(&value).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::TypeError);
+        assert_eq!(
+            error.detail(),
+            Some("expected OCID version 0 but found version 1"),
+        );
+
+        let error = OcidV0::from_redis_value(&Value::Nil).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::TypeError);
+    }
+}
@@ -0,0 +1,84 @@
+use rusqlite::types::{
+    FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef,
+};
+
+use super::OcidV0;
+
+/// Stores the 39-byte [wire format](struct.OcidV0.html#method.to_wire) of the
+/// ID as a `BLOB`.
+#[cfg_attr(docsrs, doc(cfg(feature = "rusqlite")))]
+impl ToSql for OcidV0 {
+    #[inline]
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(ValueRef::Blob(self.as_bytes())))
+    }
+}
+
+/// Fails with [`FromSqlError::Other`] if the value is not a 39-byte `BLOB`
+/// starting with version 0.
+///
+/// [`FromSqlError::Other`]: https://docs.rs/rusqlite/0.32/rusqlite/types/enum.FromSqlError.html#variant.Other
+#[cfg_attr(docsrs, doc(cfg(feature = "rusqlite")))]
+impl FromSql for OcidV0 {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let blob = value.as_blob()?;
+        OcidV0::from_wire_slice(blob)
+            .map_err(|error| FromSqlError::Other(error.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v0::LEN;
+    use rusqlite::Connection;
+
+    #[test]
+    fn round_trip() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE ids (id BLOB NOT NULL)", [])
+            .unwrap();
+
+        let id = OcidV0::new(b"hello, world").unwrap();
+        conn.execute("INSERT INTO ids (id) VALUES (?1)", [id])
+            .unwrap();
+
+        let found: OcidV0 = conn
+            .query_row("SELECT id FROM ids", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(found, id);
+
+        let len: usize = conn
+            .query_row("SELECT length(id) FROM ids", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(len, LEN);
+    }
+
+    #[test]
+    fn malformed() {
+        let id = OcidV0::new(b"hello, world").unwrap();
+        let mut wire = id.to_wire();
+
+        let error = OcidV0::column_result(ValueRef::Blob(&wire[1..]));
+        match error {
+            Err(FromSqlError::Other(error)) => assert_eq!(
+                error.to_string(),
+                "expected 39 bytes for OCID version 0 but found 38",
+            ),
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        wire[0] = 1;
+        let error = OcidV0::column_result(ValueRef::Blob(&wire));
+        match error {
+            Err(FromSqlError::Other(error)) => assert_eq!(
+                error.to_string(),
+                "expected OCID version 0 but found version 1",
+            ),
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        let error = OcidV0::column_result(ValueRef::Integer(0));
+        assert!(matches!(error, Err(FromSqlError::InvalidType)));
+    }
+}
@@ -0,0 +1,86 @@
+use core::fmt;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::OcidV0;
+
+/// Serializes the ID as its [Base64](https://en.wikipedia.org/wiki/Base64)
+/// string.
+///
+/// For the raw bytes instead, use
+/// [`ocid::serde::bytes`](serde/bytes/index.html).
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for OcidV0 {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.with_base64(|b64| serializer.serialize_str(b64))
+    }
+}
+
+/// Deserializes the ID from its [Base64](https://en.wikipedia.org/wiki/Base64)
+/// string, failing if the version is not 0.
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for OcidV0 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = OcidV0;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an OCID version 0 in Base64")
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<OcidV0, E>
+            where
+                E: de::Error,
+            {
+                s.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::v0::{ParseError, RawOcidV0, LEN};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Package {
+        id: OcidV0,
+    }
+
+    #[test]
+    fn round_trip() {
+        let id = OcidV0::new(b"hello, world").unwrap();
+        let package = Package { id };
+
+        let json = serde_json::to_string(&package).unwrap();
+        assert_eq!(json, format!(r#"{{"id":"{}"}}"#, id));
+        assert_eq!(serde_json::from_str::<Package>(&json).unwrap(), package);
+    }
+
+    #[test]
+    fn malformed() {
+        let mut bytes = [0u8; LEN];
+        bytes[0] = 1;
+        let v1 = RawOcidV0::from_bytes(bytes).with_base64(|b64| b64.to_owned());
+
+        let json = format!(r#"{{"id":"{}"}}"#, v1);
+        let error = serde_json::from_str::<Package>(&json).unwrap_err();
+        let reason = ParseError::UnexpectedVersion(1).to_string();
+        assert!(error.to_string().contains(&reason), "{}", error);
+
+        assert!(serde_json::from_str::<Package>(r#"{"id":"bad"}"#).is_err());
+        assert!(serde_json::from_str::<Package>(r#"{"id":5}"#).is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use core::{
+    cmp::Ordering,
+    simd::{cmp::SimdPartialEq, u8x16, u8x8},
+};
+
+use super::LEN;
+
+/// Compares ID bodies using [portable SIMD], returning the same result as
+/// comparing them as slices.
+///
+/// The 38 bytes are loaded as two 16-byte lanes followed by an 8-byte lane
+/// that overlaps the second by 2 bytes. Comparison stops at the first lane
+/// that differs.
+///
+/// [portable SIMD]: https://doc.rust-lang.org/nightly/core/simd/index.html
+#[inline]
+pub(super) fn cmp_body(a: &[u8; LEN - 1], b: &[u8; LEN - 1]) -> Ordering {
+    #[inline]
+    fn first_difference(a: &[u8; LEN - 1], b: &[u8; LEN - 1]) -> Option<usize> {
+        for &start in &[0, 16] {
+            let x = u8x16::from_slice(&a[start..]);
+            let y = u8x16::from_slice(&b[start..]);
+            let ne = x.simd_ne(y).to_bitmask();
+            if ne != 0 {
+                return Some(start + ne.trailing_zeros() as usize);
+            }
+        }
+
+        let start = LEN - 1 - 8;
+        let x = u8x8::from_slice(&a[start..]);
+        let y = u8x8::from_slice(&b[start..]);
+        let ne = x.simd_ne(y).to_bitmask();
+        if ne != 0 {
+            return Some(start + ne.trailing_zeros() as usize);
+        }
+
+        None
+    }
+
+    match first_difference(a, b) {
+        Some(index) => a[index].cmp(&b[index]),
+        None => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OcidV0;
+    use rand_core::RngCore;
+
+    #[test]
+    fn matches_scalar() {
+        let mut rng = rand_core::OsRng;
+
+        for _ in 0..256 {
+            let a = OcidV0::rand(&mut rng);
+            let mut b = a;
+
+            // Change a single byte so that every lane gets exercised.
+            let index = rng.next_u32() as usize % (LEN - 1);
+            b.body_mut()[index] = rng.next_u32() as u8;
+
+            for &(x, y) in &[(&a, &b), (&b, &a), (&a, &a)] {
+                let scalar = x.body().cmp(y.body());
+                assert_eq!(cmp_body(x.body(), y.body()), scalar);
+                assert_eq!(x.cmp(y), scalar);
+            }
+        }
+    }
+}
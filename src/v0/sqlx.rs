@@ -0,0 +1,86 @@
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres},
+    Decode, Encode, Type,
+};
+
+use super::{OcidV0, LEN};
+
+/// Stores the 39-byte [wire format](struct.OcidV0.html#method.to_wire) of the
+/// ID as a Postgres `BYTEA`.
+///
+/// Only Postgres is supported. The equivalent for SQLite would store the same
+/// 39 bytes as a `BLOB`.
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+impl Type<Postgres> for OcidV0 {
+    #[inline]
+    fn type_info() -> PgTypeInfo {
+        <[u8] as Type<Postgres>>::type_info()
+    }
+
+    #[inline]
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[u8] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+impl Encode<'_, Postgres> for OcidV0 {
+    #[inline]
+    fn encode_by_ref(
+        &self,
+        buf: &mut PgArgumentBuffer,
+    ) -> Result<IsNull, BoxDynError> {
+        <&[u8] as Encode<Postgres>>::encode(self.as_bytes(), buf)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> usize {
+        LEN
+    }
+}
+
+/// Fails if the value is not 39 bytes or does not start with version 0.
+#[cfg_attr(docsrs, doc(cfg(feature = "sqlx")))]
+impl<'r> Decode<'r, Postgres> for OcidV0 {
+    #[inline]
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        from_blob(<&[u8] as Decode<Postgres>>::decode(value)?)
+    }
+}
+
+fn from_blob(blob: &[u8]) -> Result<OcidV0, BoxDynError> {
+    Ok(OcidV0::from_wire_slice(blob)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn type_info() {
+        let bytea = PgTypeInfo::with_name("BYTEA");
+        assert!(<OcidV0 as Type<Postgres>>::compatible(&bytea));
+    }
+
+    #[test]
+    fn from_blob() {
+        let id = OcidV0::new(b"hello, world").unwrap();
+        assert_eq!(super::from_blob(id.as_bytes()).unwrap(), id);
+
+        let error = super::from_blob(&id.as_bytes()[1..]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected 39 bytes for OCID version 0 but found 38",
+        );
+
+        let mut wire = id.to_wire();
+        wire[0] = 1;
+        let error = super::from_blob(&wire).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected OCID version 0 but found version 1",
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use std::{io, vec};
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use super::{io::BUF_LEN, OcidV0, OcidV0Hasher};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+impl OcidV0 {
+    /// Generates an ID by asynchronously hashing all of the content of
+    /// `reader` using [BLAKE3].
+    ///
+    /// Content is read into a 16 KiB heap buffer until EOF, and each chunk is
+    /// hashed before awaiting the next read. Hashing a chunk takes only a few
+    /// microseconds, so this does not block the executor for long regardless
+    /// of the total content size. Returns `Ok(None)` if the content is larger
+    /// than [`MAX_SIZE`](#associatedconstant.MAX_SIZE).
+    ///
+    /// This is the asynchronous version of
+    /// [`from_reader`](#method.from_reader).
+    ///
+    /// [BLAKE3]: https://en.wikipedia.org/wiki/BLAKE_(hash_function)#BLAKE3
+    pub async fn from_async_read<R>(mut reader: R) -> io::Result<Option<OcidV0>>
+    where
+        R: AsyncRead + Unpin,
+    {
+        // Keep the buffer off the stack to not bloat the future.
+        let mut buf = vec![0u8; BUF_LEN];
+        let mut hasher = OcidV0Hasher::new();
+        loop {
+            match reader.read(&mut buf).await {
+                Ok(0) => return Ok(hasher.finalize()),
+                Ok(len) => hasher.update_unbounded(&buf[..len]),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_core::RngCore;
+
+    #[test]
+    fn from_async_read() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let mut content = vec![0u8; BUF_LEN * 3 + 7];
+        rand_core::OsRng.fill_bytes(&mut content);
+
+        for content in &[&[][..], b"hello, world", &content] {
+            let id = runtime.block_on(OcidV0::from_async_read(*content));
+            assert_eq!(id.unwrap(), OcidV0::new(content));
+        }
+    }
+}